@@ -1,25 +1,26 @@
-use std::io::{self};
-
-use color_eyre::Result;
 use ratatui::{prelude::*, style::palette::tailwind, symbols::border::*, widgets::*};
-use ratatui_widgets::events::*;
+use ratatui_widgets::{
+    color::ColorDepth,
+    events::*,
+    runner,
+    theme::{self, presets},
+};
 use strum::{Display, EnumIter, IntoEnumIterator};
 
 use crate::tabs::*;
 
 #[derive(Debug)]
 pub struct App {
-    state: RunningState,
     selected_tab_index: usize,
     tabs: Vec<Tab>,
+    theme_index: usize,
 }
 
-#[derive(Debug, Default, PartialEq, Eq)]
-enum RunningState {
-    #[default]
-    Running,
-    Quit,
-}
+const THEMES: [(&str, theme::Theme); 3] = [
+    ("dark", presets::DARK),
+    ("light", presets::LIGHT),
+    ("high contrast", presets::HIGH_CONTRAST),
+];
 
 #[derive(Debug, Display, EnumIter)]
 enum Tab {
@@ -36,43 +37,13 @@ impl Default for App {
 
 impl App {
     pub fn new() -> Self {
-        Self {
-            state: RunningState::Running,
+        let app = Self {
             selected_tab_index: 0,
             tabs: Tab::iter().collect(),
-        }
-    }
-
-    pub fn run(&mut self, mut terminal: Terminal<impl Backend>) -> Result<()> {
-        while self.is_running() {
-            self.draw(&mut terminal)?;
-            self.handle_events()?;
-        }
-        Ok(())
-    }
-
-    fn is_running(&self) -> bool {
-        self.state == RunningState::Running
-    }
-
-    fn draw(&mut self, terminal: &mut Terminal<impl Backend>) -> io::Result<()> {
-        terminal.draw(|frame| frame.render_widget(self, frame.area()))?;
-        Ok(())
-    }
-
-    fn handle_events(&mut self) -> Result<()> {
-        match Event::try_from(crossterm::event::read()?) {
-            Ok(event) => self.handle_event(event),
-            Err(_) => {
-                // ignore for now. Perhaps change the try_from approach to a method that returns
-                // Option instead of Result
-            }
-        }
-        Ok(())
-    }
-
-    fn quit(&mut self) {
-        self.state = RunningState::Quit;
+            theme_index: 0,
+        };
+        app.apply_theme();
+        app
     }
 }
 
@@ -82,16 +53,32 @@ impl EventHandler for App {
         match key_pressed_event.key {
             Tab => self.next_tab(),
             BackTab => self.prev_tab(),
-            Char('q') | Esc => self.quit(),
+            F(2) => self.next_theme(),
+            Char('q') | Esc => runner::request_quit(),
             _ => {
                 self.selected_tab_mut().handle_key(key_pressed_event);
             }
         }
     }
 
+    fn handle_key_release(&mut self, event: KeyReleasedEvent) {
+        self.selected_tab_mut().handle_key_release(event);
+    }
+
+    fn handle_key_repeat(&mut self, event: KeyPressedEvent) {
+        match event.key {
+            Key::Tab | Key::BackTab => self.handle_key(event),
+            _ => self.selected_tab_mut().handle_key_repeat(event),
+        }
+    }
+
     fn handle_mouse(&mut self, event: MouseEvent) {
         self.selected_tab_mut().handle_mouse(event);
     }
+
+    fn handle_tick(&mut self) {
+        self.selected_tab_mut().handle_tick();
+    }
 }
 
 impl App {
@@ -108,6 +95,16 @@ impl App {
         let tab_count = self.tabs.len();
         self.selected_tab_index = (self.selected_tab_index + tab_count - 1) % tab_count;
     }
+
+    pub fn next_theme(&mut self) {
+        self.theme_index = (self.theme_index + 1) % THEMES.len();
+        self.apply_theme();
+    }
+
+    fn apply_theme(&self) {
+        let (_, theme) = THEMES[self.theme_index];
+        theme::set_current(theme.for_color_depth(ColorDepth::detect()));
+    }
 }
 
 impl Widget for &mut App {
@@ -127,9 +124,12 @@ impl Widget for &mut App {
 
 impl App {
     fn footer(&self) -> impl Widget {
-        Line::raw("Esc: quit, Tab: next tab, Shift+Tab: prev tab")
-            .style(tailwind::SLATE.c300)
-            .centered()
+        let (theme, _) = THEMES[self.theme_index];
+        Line::raw(format!(
+            "Esc: quit, Tab: next tab, Shift+Tab: prev tab, F2: theme ({theme})"
+        ))
+        .style(tailwind::SLATE.c300)
+        .centered()
     }
 
     fn title(&self) -> impl Widget {
@@ -174,6 +174,14 @@ impl EventHandler for Tab {
         }
     }
 
+    fn handle_key_release(&mut self, event: KeyReleasedEvent) {
+        match self {
+            Tab::Buttons(buttons) => buttons.handle_key_release(event),
+            Tab::Stack(_) => {}
+            Tab::ToggleSwitch(_) => {}
+        }
+    }
+
     fn handle_mouse(&mut self, event: MouseEvent) {
         match self {
             Tab::Buttons(buttons) => buttons.handle_mouse(event),
@@ -181,6 +189,22 @@ impl EventHandler for Tab {
             Tab::ToggleSwitch(switches) => switches.handle_mouse(event),
         }
     }
+
+    fn handle_key_repeat(&mut self, event: KeyPressedEvent) {
+        match self {
+            Tab::Buttons(buttons) => buttons.handle_key_repeat(event),
+            Tab::Stack(stack) => stack.handle_key_repeat(event),
+            Tab::ToggleSwitch(switches) => switches.handle_key_repeat(event),
+        }
+    }
+
+    fn handle_tick(&mut self) {
+        match self {
+            Tab::Buttons(buttons) => buttons.handle_tick(),
+            Tab::Stack(_) => {}
+            Tab::ToggleSwitch(_) => {}
+        }
+    }
 }
 
 impl Tab {
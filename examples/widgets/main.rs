@@ -1,11 +1,7 @@
-use std::io::stdout;
+#![allow(deprecated)]
 
 use color_eyre::Result;
-use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
-    ExecutableCommand,
-};
-use ratatui::DefaultTerminal;
+use ratatui_widgets::runner;
 
 mod app;
 mod tabs {
@@ -20,15 +16,6 @@ use app::App;
 
 fn main() -> Result<()> {
     color_eyre::install()?;
-    let terminal = ratatui::init();
-    let result = run(terminal);
-    ratatui::restore();
-    result
-}
-
-fn run(terminal: DefaultTerminal) -> Result<()> {
-    stdout().execute(EnableMouseCapture)?;
-    App::new().run(terminal)?;
-    stdout().execute(DisableMouseCapture)?;
+    runner::run(App::new())?;
     Ok(())
 }
@@ -1,111 +1,174 @@
-use ratatui::{prelude::*, style::palette::tailwind};
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc, Mutex,
+};
+
+use ratatui::{prelude::*, style::palette::tailwind, widgets::BorderType};
 use ratatui_widgets::{
-    button,
-    events::{self, *},
-    Button,
+    button::IconPosition,
+    button_group,
+    events::*,
+    focus::{self, FocusManager},
+    hit_test::HitTest,
+    keymap::Keymap,
+    theme::Intent,
+    Button, ButtonGroup, ButtonMode, ButtonStyle,
 };
 
 #[derive(Debug, Clone)]
 pub struct ButtonsTab {
-    selected_index: usize,
-    buttons: Vec<Button<'static>>,
-    button_areas: Vec<Rect>,
+    focus: FocusManager,
+    /// The toolbar and the view selector
+    groups: Vec<ButtonGroup<'static>>,
+    hit_test: HitTest,
+    last_pressed: Arc<Mutex<Option<&'static str>>>,
+    presses: Arc<AtomicU32>,
 }
 
+const TOOLBAR: usize = 0;
+const VIEWS: usize = 1;
+const VIEW_NAMES: [&str; 3] = ["Day", "Week", "Month"];
+
 impl Default for ButtonsTab {
     fn default() -> Self {
-        Self {
-            selected_index: 0,
-            buttons: vec![
-                Button::new("Button 1").with_theme(button::themes::RED),
-                Button::new("Button 2").with_theme(button::themes::GREEN),
-                Button::new("Button 3").with_theme(button::themes::BLUE),
-            ],
-            button_areas: vec![],
-        }
+        // Tab is used to switch tabs, so the groups are traversed with the arrow keys
+        let keymap = Keymap::new()
+            .with_binding([Key::Up.into()], focus::Action::Previous)
+            .with_binding([Key::Down.into()], focus::Action::Next);
+        let last_pressed = Arc::new(Mutex::new(None));
+        let presses = Arc::new(AtomicU32::new(0));
+        let button = |label: &'static str, mode, intent| {
+            let last_pressed = Arc::clone(&last_pressed);
+            let presses = Arc::clone(&presses);
+            Button::new(label)
+                .with_mode(mode)
+                .with_intent(intent)
+                .with_on_press(move || {
+                    *last_pressed.lock().unwrap() = Some(label);
+                    presses.fetch_add(1, Ordering::Relaxed);
+                })
+        };
+        let toolbar = ButtonGroup::horizontal()
+            .with_constraint(Constraint::Length(20))
+            .with_keymap(
+                button_group::default_keymap(Direction::Horizontal)
+                    .with_binding([Key::Char('j').into()], focus::Action::Previous)
+                    .with_binding([Key::Char('k').into()], focus::Action::Next),
+            )
+            .with_buttons([
+                button("Momentary", ButtonMode::Momentary, Intent::Danger)
+                    .with_icon("●", IconPosition::Leading)
+                    .with_mnemonic('m')
+                    .with_shortcut("Alt+M"),
+                button("Toggle", ButtonMode::Toggle, Intent::Success)
+                    .with_style(ButtonStyle::Bordered(BorderType::Rounded))
+                    .with_mnemonic('t'),
+                button("Repeat", ButtonMode::Repeat, Intent::Primary)
+                    .with_icon("↻", IconPosition::Trailing)
+                    .with_mnemonic('r'),
+            ]);
+        let mut views = ButtonGroup::horizontal()
+            .with_constraint(Constraint::Length(10))
+            .with_spacing(0)
+            .with_buttons(VIEW_NAMES.map(|name| Button::new(name).with_style(ButtonStyle::Bracket)))
+            .with_single_selection(true);
+        views.select(1);
+        let mut tab = Self {
+            focus: FocusManager::new().with_keymap(keymap),
+            groups: vec![toolbar, views],
+            hit_test: HitTest::new(),
+            last_pressed,
+            presses,
+        };
+        tab.focus.focus(&mut tab.groups, TOOLBAR);
+        tab
     }
 }
 
 impl EventHandler for ButtonsTab {
     fn handle_key(&mut self, event: KeyPressedEvent) {
-        use events::Key::*;
+        // mnemonics press a button even when its group isn't focused
+        let mnemonic = self.groups.iter().position(|group| {
+            group
+                .buttons()
+                .iter()
+                .any(|button| button.matches_mnemonic(&event))
+        });
+        if let Some(index) = mnemonic {
+            self.focus.focus(&mut self.groups, index);
+            self.groups[index].handle_key(event);
+            return;
+        }
         match event.key {
-            Char('j') | Left => self.select_previous(),
-            Char('k') | Right => self.select_next(),
-            _ => self.selected_button_mut().handle_key(event),
+            Key::Char('d') => self.toggle_disabled(),
+            _ => self.focus.handle_key(&mut self.groups, event),
         }
     }
 
-    fn handle_mouse(&mut self, event: MouseEvent) {
-        match event.kind {
-            MouseEventKind::Down(MouseButton::Left) => self.click(event.column, event.row),
-            MouseEventKind::Up(_) => self.release(),
-            _ => {}
-        }
+    fn handle_key_release(&mut self, event: KeyReleasedEvent) {
+        self.focus
+            .handle_event(&mut self.groups, Event::KeyReleased(event));
     }
-}
 
-impl ButtonsTab {
-    pub fn selected_button_mut(&mut self) -> &mut Button<'static> {
-        &mut self.buttons[self.selected_index]
+    fn handle_key_repeat(&mut self, event: KeyPressedEvent) {
+        self.focus
+            .handle_event(&mut self.groups, Event::KeyRepeated(event));
     }
 
-    // TODO hit test should be a method on the widget / state
-    fn click(&mut self, column: u16, row: u16) {
-        for (i, area) in self.button_areas.iter().enumerate() {
-            // TODO Rect should have a contains method
-            let area_contains_click = area.left() <= column
-                && column < area.right()
-                && area.top() <= row
-                && row < area.bottom();
-            if area_contains_click {
-                // clear current selection
-                self.release();
-                self.buttons[i].toggle_press();
-                self.selected_index = i;
-                break;
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if let MouseEventKind::Down(MouseButton::Left) = event.kind {
+            if let Some(index) = self.hit_test.route(event).map(|(&index, _)| index) {
+                self.focus.focus(&mut self.groups, index);
             }
         }
+        // the groups do their own hit testing, and release their buttons when the mouse is
+        // released outside of them
+        for group in &mut self.groups {
+            group.handle_mouse(event);
+        }
     }
 
-    fn release(&mut self) {
-        self.selected_button_mut().select();
-    }
-
-    pub fn select_next(&mut self) {
-        self.select_index((self.selected_index + 1) % self.buttons.len())
-    }
-
-    pub fn select_previous(&mut self) {
-        self.select_index((self.selected_index + self.buttons.len() - 1) % self.buttons.len());
-    }
-
-    pub fn select_index(&mut self, index: usize) {
-        self.selected_button_mut().normal();
-        self.selected_index = index % self.buttons.len();
-        self.selected_button_mut().select();
+    fn handle_tick(&mut self) {
+        for group in &mut self.groups {
+            group.handle_tick();
+        }
     }
+}
 
-    pub fn press(&mut self) {
-        self.buttons[self.selected_index].toggle_press();
+impl ButtonsTab {
+    fn toggle_disabled(&mut self) {
+        let button = &mut self.groups[TOOLBAR].buttons_mut()[2];
+        button.set_disabled(!button.is_disabled());
     }
 }
 
-/// Required to be mutable because we need to store the button areas for hit testing
+/// Required to be mutable because the groups store the button areas for hit testing
 impl Widget for &mut ButtonsTab {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let layout = Layout::vertical([3, 0]);
-        let [buttons, instructions] = layout.areas(area);
-        let layout = Layout::horizontal([20, 1, 20, 1, 20, 0]);
-        let [left, _, middle, _, right, _] = layout.areas(buttons);
+        let layout = Layout::vertical([3, 1, 1, 1, 0]);
+        let [toolbar, _, views, status, instructions] = layout.areas(area);
+        let views = Rect {
+            width: views.width.min(30),
+            ..views
+        };
 
-        self.button_areas = vec![left, middle, right];
+        self.hit_test.clear();
+        for (index, area) in [(TOOLBAR, toolbar), (VIEWS, views)] {
+            self.groups[index].render(area, buf);
+            self.hit_test.register(index, area);
+        }
 
-        self.buttons[0].render(left, buf);
-        self.buttons[1].render(middle, buf);
-        self.buttons[2].render(right, buf);
+        let last_pressed = self.last_pressed.lock().unwrap().unwrap_or("none");
+        let presses = self.presses.load(Ordering::Relaxed);
+        let view = self.groups[VIEWS]
+            .selected()
+            .map_or("none", |index| VIEW_NAMES[index]);
+        Line::raw(format!(
+            "Last pressed: {last_pressed} ({presses} presses), view: {view}"
+        ))
+        .render(status, buf);
 
-        Line::raw("←/→: select, space/mouse: press")
+        Line::raw("↑/↓: group, ←/→: button, space/mouse/alt+letter: press, d: disable Repeat")
             .style(tailwind::SLATE.c300)
             .render(instructions, buf);
     }
@@ -1,88 +1,52 @@
 use ratatui::{prelude::*, style::palette::tailwind};
 use ratatui_widgets::toggle_switch::ToggleSwitch;
 use ratatui_widgets::{
-    events::{self, *},
+    events::*,
+    focus::{self, FocusManager},
+    hit_test::HitTest,
+    keymap::Keymap,
     toggle_switch::State,
 };
 
 #[derive(Debug, Clone)]
 pub struct ToggleSwitchTab {
-    selected_index: usize,
+    focus: FocusManager,
     switches: Vec<ToggleSwitch<'static>>,
-    switch_areas: Vec<Rect>,
+    hit_test: HitTest,
 }
 
 impl Default for ToggleSwitchTab {
     fn default() -> Self {
+        // Tab is used to switch tabs, so the switches are traversed with the arrow keys
+        let keymap = Keymap::new()
+            .with_binding([Key::Char('k').into()], focus::Action::Previous)
+            .with_binding([Key::Up.into()], focus::Action::Previous)
+            .with_binding([Key::Char('j').into()], focus::Action::Next)
+            .with_binding([Key::Down.into()], focus::Action::Next);
         Self {
-            selected_index: 0,
+            focus: FocusManager::new().with_keymap(keymap),
             switches: vec![
                 ToggleSwitch::new("Turned off", State::Off),
                 ToggleSwitch::new("Turned on", State::On),
             ],
-            switch_areas: vec![],
+            hit_test: HitTest::new(),
         }
     }
 }
 
 impl EventHandler for ToggleSwitchTab {
     fn handle_key(&mut self, event: KeyPressedEvent) {
-        use events::Key::*;
-        match event.key {
-            Char('k') | Up => self.select_previous(),
-            Char('j') | Down => self.select_next(),
-            _ => self.selected_switch_mut().handle_key(event),
-        }
+        self.focus.handle_key(&mut self.switches, event);
     }
 
     fn handle_mouse(&mut self, event: MouseEvent) {
-        if let MouseEventKind::Down(MouseButton::Left) = event.kind {
-            self.click(event.column, event.row)
+        let clicked = self.hit_test.dispatch(&mut self.switches, event);
+        if let (Some(index), MouseEventKind::Down(_)) = (clicked, event.kind) {
+            self.focus.focus(&mut self.switches, index);
         }
     }
 }
 
-impl ToggleSwitchTab {
-    pub fn selected_switch_mut(&mut self) -> &mut ToggleSwitch<'static> {
-        &mut self.switches[self.selected_index]
-    }
-
-    // TODO hit test should be a method on the widget / state
-    fn click(&mut self, column: u16, row: u16) {
-        for (i, area) in self.switch_areas.iter().enumerate() {
-            if area.contains(Position::new(column, row)) {
-                // clear current selection
-                self.release();
-                self.switches[i].toggle_state();
-                self.selected_index = i;
-                break;
-            }
-        }
-    }
-
-    pub fn release(&mut self) {
-        self.selected_switch_mut().blur();
-    }
-
-    pub fn select_next(&mut self) {
-        self.select_index((self.selected_index + 1) % self.switches.len())
-    }
-
-    pub fn select_previous(&mut self) {
-        self.select_index((self.selected_index + self.switches.len() - 1) % self.switches.len());
-    }
-
-    pub fn select_index(&mut self, index: usize) {
-        self.selected_switch_mut().blur();
-        self.selected_index = index % self.switches.len();
-        self.selected_switch_mut().focus();
-    }
-
-    pub fn press(&mut self) {
-        self.switches[self.selected_index].toggle_state();
-    }
-}
-
 /// Required to be mutable because we need to store the button areas for hit testing
 impl Widget for &mut ToggleSwitchTab {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -91,10 +55,11 @@ impl Widget for &mut ToggleSwitchTab {
         let layout = Layout::vertical([3, 1, 3, 0]);
         let [top, _, bottom, _] = layout.areas(buttons);
 
-        self.switch_areas = vec![top, bottom];
-
-        self.switches[0].render(top, buf);
-        self.switches[1].render(bottom, buf);
+        self.hit_test.clear();
+        for (index, area) in [top, bottom].into_iter().enumerate() {
+            self.switches[index].render(area, buf);
+            self.hit_test.register(index, area);
+        }
 
         Line::raw("←/→: select, space/mouse: press")
             .style(tailwind::SLATE.c300)
@@ -1,14 +1,131 @@
 #![allow(unused)]
 
-use ratatui::{prelude::*, widgets::Widget};
+use std::{fmt, sync::Arc};
 
+use ratatui::{
+    prelude::*,
+    style::palette::tailwind::Palette,
+    widgets::{Block, BorderType, Widget},
+};
+
+use crate::color::{self, ColorDepth};
 use crate::events::*;
+use crate::focus::Focusable;
+use crate::gesture::{Gesture, GestureHandler};
+use crate::keymap::Keymap;
+use crate::theme::{self as tokens, Intent, Tones};
 
 #[derive(Debug, Clone)]
 pub struct Button<'text> {
     text: Text<'text>,
-    theme: Theme,
+    icon: Option<Span<'text>>,
+    icon_position: IconPosition,
+    mnemonic: Option<char>,
+    shortcut: Option<Span<'text>>,
+    /// An explicit theme, otherwise the theme is derived from the current crate-wide theme
+    theme: Option<Theme>,
+    intent: Intent,
     state: State,
+    style: ButtonStyle,
+    mode: Mode,
+    hold: Option<Hold>,
+    toggled: bool,
+    hovered: bool,
+    disabled: bool,
+    keymap: Keymap<Action>,
+    on_press: Option<Callback>,
+    /// The number of times the button was activated, so containers can tell which child fired
+    activations: u64,
+}
+
+/// A callback that is called when a button is activated
+#[derive(Clone)]
+struct Callback(Arc<dyn Fn() + Send + Sync>);
+
+impl fmt::Debug for Callback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Callback(..)")
+    }
+}
+
+/// How many ticks a button pressed with a key stays pressed when no key release or repeat is
+/// reported, 150ms at the runner's [`TICK_RATE`]
+///
+/// [`TICK_RATE`]: crate::runner::TICK_RATE
+pub const RELEASE_TICKS: u32 = 3;
+
+/// How many ticks a [`Mode::Repeat`] button must be held with the mouse before it starts
+/// repeating, 400ms at the runner's [`TICK_RATE`]
+///
+/// [`TICK_RATE`]: crate::runner::TICK_RATE
+pub const REPEAT_DELAY_TICKS: u32 = 8;
+
+/// How many ticks apart a [`Mode::Repeat`] button fires while it is held with the mouse, 100ms at
+/// the runner's [`TICK_RATE`]
+///
+/// [`TICK_RATE`]: crate::runner::TICK_RATE
+pub const REPEAT_INTERVAL_TICKS: u32 = 2;
+
+/// How the button responds to being pressed
+///
+/// Releasing a button after a timeout and repeating while the mouse is held are driven by
+/// counting [`Event::Tick`]s, so the button's parent must pass ticks on to it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// Fires once when pressed. The button stays pressed until the key or mouse button is
+    /// released, or for [`RELEASE_TICKS`] on terminals that don't report key releases.
+    #[default]
+    Momentary,
+    /// Switches on or off each time it is pressed, firing each time.
+    Toggle,
+    /// Fires when pressed and again for each key repeat while held, or every
+    /// [`REPEAT_INTERVAL_TICKS`] while the mouse button is held. Useful for spinner arrows.
+    Repeat,
+}
+
+/// How the button is drawn around its label
+///
+/// The label is vertically centered in the rows that are left, and each line of the label is
+/// rendered on its own row.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonStyle {
+    /// Raised and sunken lines above and below the label, when the button is at least 2 rows high
+    #[default]
+    Bevel,
+    /// A border around the label, when the button is at least 3 rows high. Otherwise the button
+    /// is drawn flat.
+    Bordered(BorderType),
+    /// Brackets either side of the label, like `[ OK ]`. Suits single row buttons.
+    Bracket,
+    /// Just the background color
+    Flat,
+}
+
+/// Which side of the label the icon is rendered on
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IconPosition {
+    #[default]
+    Leading,
+    Trailing,
+}
+
+/// What is holding a momentary or repeat button down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Input {
+    Key,
+    Mouse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hold {
+    input: Input,
+    /// The number of ticks since the key was pressed or repeated
+    idle_ticks: u32,
+    /// The number of ticks since the button last fired
+    ticks_since_fired: u32,
+    repeating: bool,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -19,49 +136,431 @@ pub enum State {
     Pressed,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// Missing fields are deserialized from the default theme and unknown fields are an error, so
+// theme files can override just the colors they care about.
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct Theme {
     normal_text: Color,
     normal_background: Color,
+    hovered_text: Color,
+    hovered_background: Color,
     selected_text: Color,
     selected_background: Color,
     pressed_text: Color,
     pressed_background: Color,
+    toggled_text: Color,
+    toggled_background: Color,
+    disabled_text: Color,
+    disabled_background: Color,
     highlight: Color,
     shadow: Color,
+    /// The color of the border or brackets of a selected button
+    focus_ring: Color,
 }
 
+/// The theme that a button without an explicit theme or intent renders with: the
+/// [`Intent::Neutral`] tones of the current crate-wide theme. Theme files fill in the colors they
+/// leave out from this theme.
 impl Default for Theme {
     fn default() -> Self {
-        themes::NORMAL
+        Self::from_tokens(&tokens::current(), Intent::default())
+    }
+}
+
+/// Config
+impl Theme {
+    /// Builds a theme from the shades of a palette, dark shades for the backgrounds and light
+    /// shades for the text.
+    pub const fn from_palette(palette: &Palette) -> Self {
+        Self::from_tones(&Tones::dark(palette))
+            .with_disabled_text(palette.c500)
+            .with_disabled_background(palette.c900)
+            .with_focus_ring(palette.c100)
+    }
+
+    /// Builds a theme from the tones of the intent in the crate-wide design tokens.
+    pub const fn from_tokens(tokens: &tokens::Theme, intent: Intent) -> Self {
+        Self::from_tones(tokens.tones(intent))
+            .with_disabled_text(tokens.disabled_text)
+            .with_disabled_background(tokens.disabled_background)
+            .with_focus_ring(tokens.focus_ring)
+    }
+
+    /// Uses the tones for each state. The disabled colors and focus ring are placeholders for the
+    /// caller to replace.
+    const fn from_tones(tones: &Tones) -> Self {
+        Self {
+            normal_text: tones.text,
+            normal_background: tones.background,
+            hovered_text: tones.hovered_text,
+            hovered_background: tones.hovered_background,
+            selected_text: tones.focused_text,
+            selected_background: tones.focused_background,
+            pressed_text: tones.pressed_text,
+            pressed_background: tones.pressed_background,
+            toggled_text: tones.active_text,
+            toggled_background: tones.active_background,
+            disabled_text: tones.text,
+            disabled_background: tones.shadow,
+            highlight: tones.highlight,
+            shadow: tones.shadow,
+            focus_ring: tones.focused_text,
+        }
+    }
+
+    /// Builds a theme from a single color, using it as the middle shade of a palette. See
+    /// [`color::palette`].
+    ///
+    /// [`Color::Reset`] has no shades, so it gives the [`themes::NORMAL`] theme.
+    pub fn from_color(color: Color) -> Self {
+        color::palette(color).map_or(themes::NORMAL, |palette| Self::from_palette(&palette))
+    }
+
+    pub const fn with_normal_text(mut self, color: Color) -> Self {
+        self.normal_text = color;
+        self
+    }
+
+    pub const fn with_normal_background(mut self, color: Color) -> Self {
+        self.normal_background = color;
+        self
+    }
+
+    pub const fn with_hovered_text(mut self, color: Color) -> Self {
+        self.hovered_text = color;
+        self
+    }
+
+    pub const fn with_hovered_background(mut self, color: Color) -> Self {
+        self.hovered_background = color;
+        self
+    }
+
+    pub const fn with_selected_text(mut self, color: Color) -> Self {
+        self.selected_text = color;
+        self
+    }
+
+    pub const fn with_selected_background(mut self, color: Color) -> Self {
+        self.selected_background = color;
+        self
+    }
+
+    pub const fn with_pressed_text(mut self, color: Color) -> Self {
+        self.pressed_text = color;
+        self
+    }
+
+    pub const fn with_pressed_background(mut self, color: Color) -> Self {
+        self.pressed_background = color;
+        self
+    }
+
+    pub const fn with_toggled_text(mut self, color: Color) -> Self {
+        self.toggled_text = color;
+        self
+    }
+
+    pub const fn with_toggled_background(mut self, color: Color) -> Self {
+        self.toggled_background = color;
+        self
+    }
+
+    pub const fn with_disabled_text(mut self, color: Color) -> Self {
+        self.disabled_text = color;
+        self
+    }
+
+    pub const fn with_disabled_background(mut self, color: Color) -> Self {
+        self.disabled_background = color;
+        self
+    }
+
+    pub const fn with_highlight(mut self, color: Color) -> Self {
+        self.highlight = color;
+        self
+    }
+
+    pub const fn with_shadow(mut self, color: Color) -> Self {
+        self.shadow = color;
+        self
+    }
+
+    pub const fn with_focus_ring(mut self, color: Color) -> Self {
+        self.focus_ring = color;
+        self
+    }
+}
+
+impl Theme {
+    /// Replaces each color with the nearest color the terminal can display, e.g.
+    /// `theme.for_color_depth(ColorDepth::detect())`.
+    ///
+    /// Shades that are close together may become the same color on terminals with 16 colors.
+    pub fn for_color_depth(self, depth: ColorDepth) -> Self {
+        Self {
+            normal_text: depth.adapt(self.normal_text),
+            normal_background: depth.adapt(self.normal_background),
+            hovered_text: depth.adapt(self.hovered_text),
+            hovered_background: depth.adapt(self.hovered_background),
+            selected_text: depth.adapt(self.selected_text),
+            selected_background: depth.adapt(self.selected_background),
+            pressed_text: depth.adapt(self.pressed_text),
+            pressed_background: depth.adapt(self.pressed_background),
+            toggled_text: depth.adapt(self.toggled_text),
+            toggled_background: depth.adapt(self.toggled_background),
+            disabled_text: depth.adapt(self.disabled_text),
+            disabled_background: depth.adapt(self.disabled_background),
+            highlight: depth.adapt(self.highlight),
+            shadow: depth.adapt(self.shadow),
+            focus_ring: depth.adapt(self.focus_ring),
+        }
     }
 }
 
+/// The actions that can be bound to keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// Press the button, as determined by its [`Mode`]. On terminals that report key releases,
+    /// releasing the key releases the button.
+    TogglePress,
+}
+
+/// The default keymap: space and enter press the button
+pub fn default_keymap() -> Keymap<Action> {
+    Keymap::new()
+        .with_binding([Key::Char(' ').into()], Action::TogglePress)
+        .with_binding([Key::Enter.into()], Action::TogglePress)
+}
+
 /// Config
 impl<'text> Button<'text> {
     pub fn new<T: Into<Text<'text>>>(text: T) -> Self {
         Self {
             text: text.into(),
-            theme: Theme::default(),
+            icon: None,
+            icon_position: IconPosition::default(),
+            mnemonic: None,
+            shortcut: None,
+            theme: None,
+            intent: Intent::default(),
             state: State::default(),
+            style: ButtonStyle::default(),
+            mode: Mode::default(),
+            hold: None,
+            toggled: false,
+            hovered: false,
+            disabled: false,
+            keymap: default_keymap(),
+            on_press: None,
+            activations: 0,
         }
     }
 
+    /// Sets an explicit theme, instead of following the current crate-wide theme.
     pub fn with_theme(mut self, theme: Theme) -> Self {
-        self.theme = theme;
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets which tones of the crate-wide theme the button uses. This has no effect when the
+    /// button has an explicit theme.
+    pub fn with_intent(mut self, intent: Intent) -> Self {
+        self.intent = intent;
+        self
+    }
+
+    pub fn with_keymap(mut self, keymap: Keymap<Action>) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Sets a glyph that is rendered before or after the label, separated by a space.
+    pub fn with_icon<T: Into<Span<'text>>>(mut self, icon: T, position: IconPosition) -> Self {
+        self.icon = Some(icon.into());
+        self.icon_position = position;
+        self
+    }
+
+    /// Sets the mnemonic character. The first occurrence of the character in the label is
+    /// underlined, and pressing Alt and the character presses the button.
+    ///
+    /// The button only receives keys while it is focused, so containers should also pass key
+    /// events that [match](Button::matches_mnemonic) the mnemonic of an unfocused button to it.
+    pub fn with_mnemonic(mut self, mnemonic: char) -> Self {
+        self.mnemonic = Some(mnemonic);
+        self
+    }
+
+    /// Sets a hint, such as `"Ctrl+S"`, that is rendered right aligned when there is room for it.
+    ///
+    /// The hint is only displayed. Binding the shortcut is up to the application.
+    pub fn with_shortcut<T: Into<Span<'text>>>(mut self, shortcut: T) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether a [`Mode::Toggle`] button starts switched on.
+    pub fn with_toggled(mut self, toggled: bool) -> Self {
+        self.toggled = toggled;
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.set_disabled(disabled);
+        self
+    }
+
+    /// Sets a callback that is called each time the button is pressed with a key or the mouse.
+    ///
+    /// The callback cannot borrow the application state that owns the button, so it typically
+    /// sends a message on a channel or updates shared state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::{
+    ///     atomic::{AtomicU32, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// use ratatui_widgets::events::{EventHandler, Key};
+    /// use ratatui_widgets::Button;
+    ///
+    /// let presses = Arc::new(AtomicU32::new(0));
+    /// let counter = Arc::clone(&presses);
+    /// let mut button = Button::new("OK").with_on_press(move || {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    /// button.handle_key(Key::Enter.into());
+    /// assert_eq!(presses.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn with_on_press<F: Fn() + Send + Sync + 'static>(mut self, on_press: F) -> Self {
+        self.on_press = Some(Callback(Arc::new(on_press)));
         self
     }
 }
 
+/// A disabled button ignores all events.
 impl EventHandler for Button<'_> {
     fn handle_key(&mut self, key_event: KeyPressedEvent) {
-        match key_event.key {
-            Key::Char(' ') | Key::Enter => self.toggle_press(),
+        if self.disabled {
+            return;
+        }
+        if self.matches_mnemonic(&key_event) {
+            self.press_with(Input::Key, false);
+            return;
+        }
+        for action in self.keymap.resolve(key_event) {
+            match action {
+                Action::TogglePress => self.press_with(Input::Key, false),
+            }
+        }
+    }
+
+    fn handle_key_release(&mut self, key_event: KeyReleasedEvent) {
+        if self.disabled {
+            return;
+        }
+        let key = KeyPressedEvent {
+            key: key_event.key,
+            modifiers: key_event.modifiers,
+        };
+        if self.matches_mnemonic(&key) {
+            self.release();
+            return;
+        }
+        match self.keymap.action([key]) {
+            Some(Action::TogglePress) => self.release(),
+            None => {}
+        }
+    }
+
+    /// Holding the key down keeps a momentary button pressed and fires a repeat button again. A
+    /// toggle button ignores repeats.
+    fn handle_key_repeat(&mut self, key_event: KeyPressedEvent) {
+        if self.disabled || self.mode == Mode::Toggle {
+            return;
+        }
+        if self.matches_mnemonic(&key_event) {
+            self.press_with(Input::Key, true);
+            return;
+        }
+        match self.keymap.action([key_event]) {
+            Some(Action::TogglePress) => self.press_with(Input::Key, true),
+            None => {}
+        }
+    }
+
+    /// Pressing the left mouse button presses the button and releasing it releases the button.
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if self.disabled {
+            return;
+        }
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.press_with(Input::Mouse, false);
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.release(),
+            _ => {}
+        }
+    }
+
+    /// Releases a button pressed with a key after [`RELEASE_TICKS`], and repeats a
+    /// [`Mode::Repeat`] button held with the mouse.
+    fn handle_tick(&mut self) {
+        if self.disabled {
+            return;
+        }
+        if let Some(Action::TogglePress) = self.keymap.expire() {
+            self.press_with(Input::Key, false);
+        }
+        self.tick();
+    }
+}
+
+impl GestureHandler for Button<'_> {
+    fn handle_gesture(&mut self, gesture: Gesture) {
+        match gesture {
+            Gesture::HoverEnter { .. } => self.set_hovered(true),
+            Gesture::HoverLeave { .. } => self.set_hovered(false),
             _ => {}
         }
     }
 }
 
+/// A button is focused when it is selected or pressed.
+impl Focusable for Button<'_> {
+    fn is_focused(&self) -> bool {
+        self.state != State::Normal
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        match (focused, self.state) {
+            (true, State::Normal) => self.select(),
+            (false, _) => self.normal(),
+            (true, _) => {}
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        !self.disabled
+    }
+}
+
 impl Button<'_> {
     pub fn toggle_press(&mut self) {
         match self.state {
@@ -75,111 +574,816 @@ impl Button<'_> {
         self.state = State::Pressed;
     }
 
+    /// Releases a pressed button, leaving it selected.
+    pub fn release(&mut self) {
+        self.hold = None;
+        if self.state == State::Pressed {
+            self.select();
+        }
+    }
+
     pub fn normal(&mut self) {
+        self.hold = None;
         self.state = State::Normal;
     }
 
     pub fn select(&mut self) {
         self.state = State::Selected;
     }
+
+    /// Returns the explicit theme, or the theme derived from the current crate-wide theme.
+    pub fn theme(&self) -> Theme {
+        self.theme
+            .unwrap_or_else(|| Theme::from_tokens(&tokens::current(), self.intent))
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Changes how the button responds to being pressed, releasing it if it is held.
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+        self.release();
+    }
+
+    pub fn is_toggled(&self) -> bool {
+        self.toggled
+    }
+
+    /// Switches a [`Mode::Toggle`] button on or off without firing it.
+    pub fn set_toggled(&mut self, toggled: bool) {
+        self.toggled = toggled;
+    }
+
+    pub fn mnemonic(&self) -> Option<char> {
+        self.mnemonic
+    }
+
+    /// Returns true if the key is Alt and the button's mnemonic, ignoring case.
+    pub fn matches_mnemonic(&self, key_event: &KeyPressedEvent) -> bool {
+        let Some(mnemonic) = self.mnemonic else {
+            return false;
+        };
+        match key_event.key {
+            Key::Char(c) => {
+                key_event.modifiers == KeyModifiers::ALT
+                    && c.to_lowercase().eq(mnemonic.to_lowercase())
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Sets whether the mouse is over the button. A hovered button is highlighted unless it is
+    /// selected or pressed.
+    pub fn set_hovered(&mut self, hovered: bool) {
+        self.hovered = hovered;
+    }
+
+    /// Calls the `on_press` callback, unless the button is disabled.
+    ///
+    /// Returns `true` if the button was activated. This is called when the button is pressed by
+    /// a key or the mouse, but not by [`Button::press`].
+    pub fn activate(&mut self) -> bool {
+        if self.disabled {
+            return false;
+        }
+        self.activations += 1;
+        if let Some(Callback(on_press)) = &self.on_press {
+            on_press();
+        }
+        true
+    }
+
+    pub(crate) fn activations(&self) -> u64 {
+        self.activations
+    }
+
+    /// Presses the button as determined by its mode. A key repeat fires a repeat button, and only
+    /// keeps a momentary button held.
+    fn press_with(&mut self, input: Input, repeated: bool) {
+        if self.mode == Mode::Toggle {
+            self.toggled = !self.toggled;
+            self.activate();
+            return;
+        }
+        let fire = match &mut self.hold {
+            // the key was repeated, or pressed again before the previous press timed out
+            Some(hold) if hold.input == input => {
+                hold.idle_ticks = 0;
+                let fire = !repeated || self.mode == Mode::Repeat;
+                if fire {
+                    hold.ticks_since_fired = 0;
+                }
+                fire
+            }
+            _ => {
+                self.press();
+                self.hold = Some(Hold {
+                    input,
+                    idle_ticks: 0,
+                    ticks_since_fired: 0,
+                    repeating: false,
+                });
+                true
+            }
+        };
+        if fire {
+            self.activate();
+        }
+    }
+
+    /// Counts a tick, releasing a key press that has timed out and firing a repeat button held
+    /// with the mouse when it is due.
+    fn tick(&mut self) {
+        let Some(hold) = &mut self.hold else {
+            return;
+        };
+        match hold.input {
+            Input::Key => {
+                hold.idle_ticks += 1;
+                if hold.idle_ticks >= RELEASE_TICKS {
+                    self.release();
+                }
+            }
+            Input::Mouse if self.mode == Mode::Repeat => {
+                hold.ticks_since_fired += 1;
+                let delay = if hold.repeating {
+                    REPEAT_INTERVAL_TICKS
+                } else {
+                    REPEAT_DELAY_TICKS
+                };
+                if hold.ticks_since_fired >= delay {
+                    hold.ticks_since_fired = 0;
+                    hold.repeating = true;
+                    self.activate();
+                }
+            }
+            Input::Mouse => {}
+        }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Disables or enables the button. A disabled button is rendered with the disabled theme
+    /// colors, ignores events and cannot be focused. Disabling a button returns it to the normal
+    /// state.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.normal();
+        }
+    }
 }
 
 impl Widget for &Button<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let theme = self.theme;
+        let monochrome = color::depth() == ColorDepth::Monochrome;
+        let theme = if monochrome {
+            self.theme().for_color_depth(ColorDepth::Monochrome)
+        } else {
+            self.theme()
+        };
 
         // these are wrong
+        let toggled = self.toggled && self.mode == Mode::Toggle;
         let fg = match self.state {
+            _ if self.disabled => theme.disabled_text,
+            _ if toggled => theme.toggled_text,
+            State::Normal if self.hovered => theme.hovered_text,
             State::Normal => theme.normal_text,
             State::Selected => theme.selected_text,
             State::Pressed => theme.pressed_text,
         };
         let bg = match self.state {
+            _ if self.disabled => theme.disabled_background,
+            _ if toggled => theme.toggled_background,
+            State::Normal if self.hovered => theme.hovered_background,
             State::Normal => theme.normal_background,
             State::Selected => theme.selected_background,
             State::Pressed => theme.pressed_background,
         };
-        let (top, bottom) = if self.state == State::Pressed {
+
+        buf.set_style(area, (fg, bg));
+        if toggled && self.state == State::Selected {
+            // the toggled colors hide the selection, so show it with the text instead
+            buf.set_style(area, Modifier::BOLD);
+        }
+
+        // the color of the border and brackets
+        let outline = match self.state {
+            _ if self.disabled => theme.disabled_text,
+            State::Selected | State::Pressed => theme.focus_ring,
+            State::Normal if toggled => fg,
+            State::Normal => theme.highlight,
+        };
+        let content = match self.style {
+            ButtonStyle::Bevel => self.render_bevel(area, buf, &theme, toggled, bg),
+            ButtonStyle::Bordered(border_type) if area.height >= 3 => {
+                let block = Block::bordered()
+                    .border_type(border_type)
+                    .border_style(outline);
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            ButtonStyle::Bracket if area.width >= 2 => {
+                for row in area.rows() {
+                    buf[(row.left(), row.y)].set_symbol("[").set_fg(outline);
+                    buf[(row.right() - 1, row.y)]
+                        .set_symbol("]")
+                        .set_fg(outline);
+                }
+                Rect {
+                    x: area.x + 1,
+                    width: area.width - 2,
+                    ..area
+                }
+            }
+            ButtonStyle::Bordered(_) | ButtonStyle::Bracket | ButtonStyle::Flat => area,
+        };
+        if monochrome {
+            buf.set_style(content, self.monochrome_modifier(toggled));
+        }
+        self.render_content(content, buf, monochrome);
+    }
+}
+
+impl Button<'_> {
+    /// Renders the bevel lines, if there is room for them, and returns the area between them.
+    fn render_bevel(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        theme: &Theme,
+        toggled: bool,
+        bg: Color,
+    ) -> Rect {
+        let (top, bottom) = if self.disabled {
+            // a disabled button is rendered flat
+            (bg, bg)
+        } else if self.state == State::Pressed || toggled {
             (theme.shadow, theme.highlight)
         } else {
             (theme.highlight, theme.shadow)
         };
-
-        buf.set_style(area, (fg, bg));
-
-        let rows = area.rows().collect::<Vec<_>>();
-        let last_index = rows.len().saturating_sub(1);
-        let (first, middle, last) = match rows.len() {
-            0 | 1 => (None, &rows[..], None),
-            2 => (None, &rows[..last_index], Some(rows[last_index])),
-            _ => (Some(rows[0]), &rows[1..last_index], Some(rows[last_index])),
+        let (has_top, has_bottom) = match area.height {
+            0 | 1 => (false, false),
+            2 => (false, true),
+            _ => (true, true),
         };
 
-        // render top line if there's enough space
-        if let Some(first) = first {
+        if has_top {
+            let first = Rect { height: 1, ..area };
             "▔"
                 .repeat(area.width as usize)
                 .fg(top)
                 .bg(bg)
                 .render(first, buf);
         }
-        // render bottom line if there's enough space
-        if let Some(last) = last {
+        if has_bottom {
+            let last = Rect {
+                y: area.bottom() - 1,
+                height: 1,
+                ..area
+            };
             "▁"
                 .repeat(area.width as usize)
                 .fg(bottom)
                 .bg(bg)
                 .render(last, buf);
         }
-        self.text.clone().centered().render(middle[0], buf);
+        Rect {
+            y: area.y + u16::from(has_top),
+            height: area.height - u16::from(has_top) - u16::from(has_bottom),
+            ..area
+        }
+    }
+
+    /// Returns the modifier that shows the state of the button when colors are unavailable.
+    fn monochrome_modifier(&self, toggled: bool) -> Modifier {
+        let modifier = match self.state {
+            _ if self.disabled => Modifier::DIM,
+            State::Normal if self.hovered => Modifier::UNDERLINED,
+            State::Normal => Modifier::empty(),
+            State::Selected => Modifier::REVERSED,
+            State::Pressed => Modifier::REVERSED | Modifier::BOLD,
+        };
+        if toggled {
+            modifier | Modifier::BOLD
+        } else {
+            modifier
+        }
     }
+
+    /// Renders the lines of the label vertically centered in the area. The first line has the
+    /// icon and the shortcut hint. Each line is truncated when the area is too narrow, after
+    /// dropping the hint.
+    fn render_content(&self, area: Rect, buf: &mut Buffer, monochrome: bool) {
+        buf.set_style(area, self.text.style);
+        let lines = self.lines(monochrome);
+        let height = (lines.len() as u16).min(area.height);
+        let top = area.y + (area.height - height) / 2;
+        for (index, line) in lines.into_iter().take(height as usize).enumerate() {
+            let row = Rect {
+                y: top + index as u16,
+                height: 1,
+                ..area
+            };
+            let shortcut = self.shortcut.as_ref().filter(|_| index == 0);
+            self.render_line(line, shortcut, row, buf);
+        }
+    }
+
+    fn render_line(&self, line: Line, shortcut: Option<&Span>, area: Rect, buf: &mut Buffer) {
+        let alignment = line
+            .alignment
+            .or(self.text.alignment)
+            .unwrap_or(Alignment::Center);
+        let line_width = line.width() as u16;
+        let mut line_area = area;
+        if let Some(shortcut) = shortcut {
+            let hint_width = shortcut.width() as u16;
+            // a column of padding on either side of the hint
+            if line_width + hint_width + 2 <= area.width {
+                let hint_area = Rect {
+                    x: area.right() - hint_width - 1,
+                    width: hint_width,
+                    ..area
+                };
+                shortcut.clone().dim().render(hint_area, buf);
+                line_area.width -= hint_width + 2;
+            }
+        }
+        truncate(line, line_area.width as usize)
+            .alignment(alignment)
+            .render(line_area, buf);
+    }
+
+    /// The lines of the text, with the icon added to the first line and the mnemonic underlined.
+    /// In monochrome, toggle buttons also get a check box, as bold text is easily missed.
+    fn lines(&self, monochrome: bool) -> Vec<Line<'_>> {
+        let mut lines = self.text.lines.clone();
+        if lines.is_empty() {
+            lines.push(Line::default());
+        }
+        if let Some(mnemonic) = self.mnemonic {
+            for line in &mut lines {
+                if underline_mnemonic(line, mnemonic) {
+                    break;
+                }
+            }
+        }
+        if let Some(icon) = &self.icon {
+            let label = &mut lines[0];
+            match self.icon_position {
+                IconPosition::Leading => {
+                    label.spans.splice(0..0, [icon.clone(), Span::raw(" ")]);
+                }
+                IconPosition::Trailing => {
+                    label.spans.extend([Span::raw(" "), icon.clone()]);
+                }
+            }
+        }
+        if monochrome && self.mode == Mode::Toggle {
+            let check_box = if self.toggled { "[x]" } else { "[ ]" };
+            lines[0]
+                .spans
+                .splice(0..0, [Span::raw(check_box), Span::raw(" ")]);
+        }
+        lines
+    }
+}
+
+/// Underlines the first occurrence of the mnemonic in the line, ignoring case. Returns false if
+/// the line does not contain the mnemonic.
+fn underline_mnemonic(line: &mut Line<'_>, mnemonic: char) -> bool {
+    let is_mnemonic = |c: char| c.to_lowercase().eq(mnemonic.to_lowercase());
+    let found = line.spans.iter().enumerate().find_map(|(index, span)| {
+        let (start, c) = span.content.char_indices().find(|&(_, c)| is_mnemonic(c))?;
+        Some((index, start, start + c.len_utf8()))
+    });
+    let Some((index, start, end)) = found else {
+        return false;
+    };
+    let span = line.spans.remove(index);
+    let parts = [
+        Span::styled(span.content[..start].to_string(), span.style),
+        Span::styled(span.content[start..end].to_string(), span.style).underlined(),
+        Span::styled(span.content[end..].to_string(), span.style),
+    ];
+    line.spans.splice(index..index, parts);
+    true
+}
+
+/// Truncates the line to the width, replacing the last visible character with an ellipsis.
+fn truncate(line: Line<'_>, width: usize) -> Line<'_> {
+    if line.width() <= width {
+        return line;
+    }
+    let available = width.saturating_sub(1);
+    let mut used = 0;
+    let mut spans = Vec::new();
+    let mut ellipsis_style = Style::default();
+    'spans: for span in &line.spans {
+        ellipsis_style = span.style;
+        let mut content = String::new();
+        for grapheme in span.styled_graphemes(Style::default()) {
+            let grapheme_width = Span::raw(grapheme.symbol).width();
+            if used + grapheme_width > available {
+                spans.push(Span::styled(content, span.style));
+                break 'spans;
+            }
+            used += grapheme_width;
+            content.push_str(grapheme.symbol);
+        }
+        spans.push(Span::styled(content, span.style));
+    }
+    if width > 0 {
+        spans.push(Span::styled("…", ellipsis_style));
+    }
+    Line { spans, ..line }
 }
 
 pub mod themes {
     use super::Theme;
     use ratatui::style::palette::tailwind;
 
-    pub const NORMAL: Theme = Theme {
-        normal_text: tailwind::GRAY.c200,
-        normal_background: tailwind::GRAY.c800,
-        selected_text: tailwind::GRAY.c100,
-        selected_background: tailwind::GRAY.c700,
-        pressed_text: tailwind::GRAY.c300,
-        pressed_background: tailwind::GRAY.c900,
-        highlight: tailwind::GRAY.c600,
-        shadow: tailwind::GRAY.c950,
-    };
+    pub const NORMAL: Theme = Theme::from_palette(&tailwind::GRAY);
+    pub const RED: Theme = Theme::from_palette(&tailwind::RED);
+    pub const GREEN: Theme = Theme::from_palette(&tailwind::GREEN);
+    pub const BLUE: Theme = Theme::from_palette(&tailwind::BLUE);
+}
 
-    pub const RED: Theme = Theme {
-        normal_text: tailwind::RED.c200,
-        normal_background: tailwind::RED.c800,
-        selected_text: tailwind::RED.c100,
-        selected_background: tailwind::RED.c700,
-        pressed_text: tailwind::RED.c300,
-        pressed_background: tailwind::RED.c900,
-        highlight: tailwind::RED.c600,
-        shadow: tailwind::RED.c950,
-    };
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
-    pub const GREEN: Theme = Theme {
-        normal_text: tailwind::GREEN.c200,
-        normal_background: tailwind::GREEN.c800,
-        selected_text: tailwind::GREEN.c100,
-        selected_background: tailwind::GREEN.c700,
-        pressed_text: tailwind::GREEN.c300,
-        pressed_background: tailwind::GREEN.c900,
-        highlight: tailwind::GREEN.c600,
-        shadow: tailwind::GREEN.c950,
-    };
+    use rstest::rstest;
 
-    pub const BLUE: Theme = Theme {
-        normal_text: tailwind::BLUE.c200,
-        normal_background: tailwind::BLUE.c800,
-        selected_text: tailwind::BLUE.c100,
-        selected_background: tailwind::BLUE.c700,
-        pressed_text: tailwind::BLUE.c300,
-        pressed_background: tailwind::BLUE.c900,
-        highlight: tailwind::BLUE.c600,
-        shadow: tailwind::BLUE.c950,
-    };
+    use super::*;
+
+    #[test]
+    fn theme_from_color() {
+        let theme = Theme::from_color(Color::Rgb(200, 100, 0));
+        assert_eq!(theme.disabled_text, Color::Rgb(200, 100, 0));
+        assert_eq!(theme.normal_background, Color::Rgb(90, 45, 0));
+        assert_eq!(Theme::from_color(Color::Reset), themes::NORMAL);
+
+        let theme = theme.with_shadow(Color::Black);
+        assert_eq!(theme.shadow, Color::Black);
+    }
+
+    #[rstest]
+    #[case::indexed(ColorDepth::Indexed256)]
+    #[case::ansi(ColorDepth::Ansi16)]
+    fn theme_for_color_depth(#[case] depth: ColorDepth) {
+        let theme = themes::BLUE.for_color_depth(depth);
+        let is_rgb = |color| matches!(color, Color::Rgb(..));
+        assert!(!is_rgb(theme.normal_text) && !is_rgb(theme.normal_background));
+        assert!(!is_rgb(theme.highlight) && !is_rgb(theme.shadow));
+    }
+
+    #[test]
+    fn theme_follows_tokens() {
+        let button = Button::new("Delete").with_intent(Intent::Danger);
+        assert_eq!(
+            button.theme(),
+            Theme::from_tokens(&tokens::presets::DARK, Intent::Danger)
+        );
+
+        tokens::set_current(tokens::presets::HIGH_CONTRAST);
+        assert_eq!(button.theme().normal_text, Color::White);
+        assert_eq!(button.theme().focus_ring, Color::LightYellow);
+        tokens::set_current(tokens::presets::DARK);
+
+        let button = button.with_theme(themes::GREEN);
+        assert_eq!(button.theme(), themes::GREEN);
+    }
+
+    #[test]
+    fn default_theme_matches_new_button() {
+        assert_eq!(Theme::default(), Button::new("OK").theme());
+        tokens::set_current(tokens::presets::LIGHT);
+        assert_eq!(Theme::default(), Button::new("OK").theme());
+        tokens::set_current(tokens::presets::DARK);
+    }
+
+    /// Renders the button on a single row and returns the symbols
+    fn render_row(button: &Button, width: u16) -> String {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        button.render(buf.area, &mut buf);
+        buf.content.iter().map(|cell| cell.symbol()).collect()
+    }
+
+    /// Renders the button and returns the symbols of each row
+    fn render_rows(button: &Button, width: u16, height: u16) -> Vec<String> {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        button.render(buf.area, &mut buf);
+        buf.content
+            .chunks(width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+            .collect()
+    }
+
+    #[rstest]
+    #[case::bevel(ButtonStyle::Bevel, ["▔▔▔▔▔▔", "  OK  ", "▁▁▁▁▁▁"])]
+    #[case::plain(ButtonStyle::Bordered(BorderType::Plain), ["┌────┐", "│ OK │", "└────┘"])]
+    #[case::rounded(ButtonStyle::Bordered(BorderType::Rounded), ["╭────╮", "│ OK │", "╰────╯"])]
+    #[case::double(ButtonStyle::Bordered(BorderType::Double), ["╔════╗", "║ OK ║", "╚════╝"])]
+    #[case::bracket(ButtonStyle::Bracket, ["[    ]", "[ OK ]", "[    ]"])]
+    #[case::flat(ButtonStyle::Flat, ["      ", "  OK  ", "      "])]
+    fn styles(#[case] style: ButtonStyle, #[case] expected: [&str; 3]) {
+        let button = Button::new("OK").with_style(style);
+        assert_eq!(render_rows(&button, 6, 3), expected);
+    }
+
+    #[rstest]
+    #[case::bordered(ButtonStyle::Bordered(BorderType::Plain))]
+    #[case::bracket(ButtonStyle::Bracket)]
+    #[case::flat(ButtonStyle::Flat)]
+    fn style_single_row(#[case] style: ButtonStyle) {
+        let button = Button::new("OK").with_style(style);
+        let expected = match style {
+            ButtonStyle::Bracket => "[ OK ]",
+            _ => "  OK  ",
+        };
+        assert_eq!(render_rows(&button, 6, 1), [expected]);
+    }
+
+    #[rstest]
+    #[case::fits(4, ["    ", "Save", " As ", "    "])]
+    #[case::clipped(1, ["Save"])]
+    fn multiple_lines(#[case] height: u16, #[case] expected: impl AsRef<[&'static str]>) {
+        let button =
+            Button::new(Text::from(vec!["Save".into(), "As".into()])).with_style(ButtonStyle::Flat);
+        assert_eq!(render_rows(&button, 4, height), expected.as_ref());
+    }
+
+    #[test]
+    fn empty_area() {
+        let button = Button::new("OK");
+        assert!(render_rows(&button, 4, 0).is_empty());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 0, 3));
+        button.render(buf.area, &mut buf);
+    }
+
+    #[test]
+    fn monochrome() {
+        color::set_depth(ColorDepth::Monochrome);
+        let mut button = Button::new("OK")
+            .with_style(ButtonStyle::Bracket)
+            .with_mode(Mode::Toggle);
+        let render = |button: &Button| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            button.render(buf.area, &mut buf);
+            buf
+        };
+
+        let buf = render(&button);
+        assert_eq!(buf, Buffer::with_lines(["[ [ ] OK ]"]));
+        assert!(buf.content.iter().all(|cell| cell.bg == Color::Reset));
+
+        button.select();
+        button.set_toggled(true);
+        let buf = render(&button);
+        let mut expected = Buffer::with_lines(["[ [x] OK ]"]);
+        expected.set_style(expected.area, Modifier::BOLD);
+        expected.set_style(Rect::new(1, 0, 8, 1), Modifier::REVERSED);
+        assert_eq!(buf, expected);
+        color::set_depth(ColorDepth::TrueColor);
+    }
+
+    #[rstest]
+    #[case::fits(10, "   Save   ")]
+    #[case::exact(4, "Save")]
+    #[case::truncated(3, "Sa…")]
+    #[case::ellipsis_only(1, "…")]
+    fn truncates_label(#[case] width: u16, #[case] expected: &str) {
+        assert_eq!(render_row(&Button::new("Save"), width), expected);
+    }
+
+    #[rstest]
+    #[case::leading(IconPosition::Leading, "  ✓ Save  ")]
+    #[case::trailing(IconPosition::Trailing, "  Save ✓  ")]
+    fn icon(#[case] position: IconPosition, #[case] expected: &str) {
+        let button = Button::new("Save").with_icon("✓", position);
+        assert_eq!(render_row(&button, 10), expected);
+    }
+
+    #[rstest]
+    #[case::with_hint(20, "    Save     Ctrl+S ")]
+    #[case::without_hint(10, "   Save   ")]
+    fn shortcut(#[case] width: u16, #[case] expected: &str) {
+        let button = Button::new("Save").with_shortcut("Ctrl+S");
+        assert_eq!(render_row(&button, width), expected);
+    }
+
+    #[test]
+    fn mnemonic_is_underlined() {
+        let button = Button::new("Save As").with_mnemonic('a');
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+        button.render(buf.area, &mut buf);
+        let underlined = buf
+            .content
+            .iter()
+            .map(|cell| cell.modifier.contains(Modifier::UNDERLINED))
+            .collect::<Vec<_>>();
+        assert_eq!(underlined, [false, true, false, false, false, false, false]);
+    }
+
+    #[test]
+    fn mnemonic_presses_button() {
+        let (button, fired) = button(Mode::Momentary);
+        let mut button = button.with_mnemonic('O');
+        let alt_o = KeyPressedEvent {
+            key: Key::Char('o'),
+            modifiers: KeyModifiers::ALT,
+        };
+        assert!(button.matches_mnemonic(&alt_o));
+        assert!(!button.matches_mnemonic(&Key::Char('o').into()));
+        button.handle_key(alt_o);
+        assert_eq!(button.state, State::Pressed);
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Button>();
+        assert_send_sync::<crate::ButtonGroup>();
+    }
+
+    /// Returns a button in the given mode and a counter of the times it fired
+    fn button(mode: Mode) -> (Button<'static>, Arc<AtomicU32>) {
+        let fired = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&fired);
+        let button = Button::new("OK").with_mode(mode).with_on_press(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        (button, fired)
+    }
+
+    /// Counts the given number of ticks.
+    fn tick(button: &mut Button, ticks: u32) {
+        for _ in 0..ticks {
+            button.handle_tick();
+        }
+    }
+
+    #[test]
+    fn momentary_fires_once_while_held() {
+        let (mut button, fired) = button(Mode::Momentary);
+        button.handle_key(Key::Enter.into());
+        tick(&mut button, 1);
+        button.handle_key_repeat(Key::Enter.into());
+        assert_eq!(button.state, State::Pressed);
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+
+        button.release();
+        assert_eq!(button.state, State::Selected);
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn momentary_fires_for_each_key_press() {
+        let (mut button, fired) = button(Mode::Momentary);
+        button.handle_key(Key::Enter.into());
+        tick(&mut button, 1);
+        button.handle_key(Key::Enter.into());
+        assert_eq!(button.state, State::Pressed);
+        assert_eq!(fired.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn momentary_key_press_times_out() {
+        let (mut button, fired) = button(Mode::Momentary);
+        button.press_with(Input::Key, false);
+        tick(&mut button, RELEASE_TICKS - 1);
+        assert_eq!(button.state, State::Pressed);
+        button.press_with(Input::Key, true);
+        tick(&mut button, RELEASE_TICKS - 1);
+        assert_eq!(button.state, State::Pressed);
+        tick(&mut button, 1);
+        assert_eq!(button.state, State::Selected);
+
+        button.press_with(Input::Key, false);
+        assert_eq!(fired.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn momentary_mouse_press_waits_for_release() {
+        let (mut button, fired) = button(Mode::Momentary);
+        button.press_with(Input::Mouse, false);
+        tick(&mut button, REPEAT_DELAY_TICKS * 2);
+        assert_eq!(button.state, State::Pressed);
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn toggle() {
+        let (mut button, fired) = button(Mode::Toggle);
+        button.press_with(Input::Key, false);
+        assert!(button.is_toggled());
+        assert_eq!(button.state, State::Normal);
+        button.press_with(Input::Mouse, false);
+        assert!(!button.is_toggled());
+        assert_eq!(fired.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn toggle_ignores_key_repeat() {
+        let (mut button, fired) = button(Mode::Toggle);
+        button.handle_key(Key::Enter.into());
+        button.handle_key_repeat(Key::Enter.into());
+        assert!(button.is_toggled());
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn repeat_fires_on_key_repeat() {
+        let (mut button, fired) = button(Mode::Repeat);
+        button.handle_key(Key::Enter.into());
+        button.handle_key_repeat(Key::Enter.into());
+        button.handle_key_repeat(Key::Enter.into());
+        assert_eq!(button.state, State::Pressed);
+        assert_eq!(fired.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn repeat_fires_while_mouse_held() {
+        let (mut button, fired) = button(Mode::Repeat);
+        button.press_with(Input::Mouse, false);
+        tick(&mut button, REPEAT_DELAY_TICKS - 1);
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+        tick(&mut button, 1);
+        assert_eq!(fired.load(Ordering::Relaxed), 2);
+        tick(&mut button, REPEAT_INTERVAL_TICKS);
+        assert_eq!(fired.load(Ordering::Relaxed), 3);
+
+        button.release();
+        tick(&mut button, REPEAT_DELAY_TICKS * 10);
+        assert_eq!(button.state, State::Selected);
+        assert_eq!(fired.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn disabled_ignores_keys_and_mouse() {
+        let (mut button, fired) = button(Mode::Momentary);
+        button.set_disabled(true);
+        button.handle_key(Key::Enter.into());
+        button.handle_mouse(MouseEvent {
+            column: 0,
+            row: 0,
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::empty(),
+        });
+        assert_eq!(button.state, State::Normal);
+        assert_eq!(fired.load(Ordering::Relaxed), 0);
+
+        button.press();
+        button.handle_key_release(KeyReleasedEvent {
+            key: Key::Enter,
+            modifiers: KeyModifiers::empty(),
+        });
+        assert_eq!(button.state, State::Pressed);
+    }
+
+    #[test]
+    fn disabled_renders_with_disabled_colors() {
+        let theme = themes::GREEN
+            .with_disabled_text(Color::Red)
+            .with_disabled_background(Color::Blue);
+        let mut button = Button::new("OK")
+            .with_theme(theme)
+            .with_style(ButtonStyle::Flat);
+        button.select();
+        button.set_disabled(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        button.render(buf.area, &mut buf);
+        assert!(buf
+            .content
+            .iter()
+            .all(|cell| cell.fg == Color::Red && cell.bg == Color::Blue));
+    }
+
+    #[test]
+    fn disabled_ignores_ticks() {
+        let (mut button, fired) = button(Mode::Repeat);
+        button.press_with(Input::Mouse, false);
+        button.set_disabled(true);
+        button.handle_tick();
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+        assert_eq!(button.state, State::Normal);
+    }
 }
@@ -0,0 +1,425 @@
+use std::iter;
+
+use ratatui::prelude::*;
+
+use crate::button::{Button, Mode};
+use crate::events::*;
+use crate::focus::{self, FocusManager, Focusable};
+use crate::gesture::GestureRecognizer;
+use crate::hit_test::HitTest;
+use crate::keymap::Keymap;
+
+/// A row or column of buttons with roving focus, e.g. a toolbar or the buttons of a dialog
+///
+/// The arrow keys along the direction of the group move focus between the buttons, and other keys
+/// go to the focused button. A button's mnemonic presses it even when another button is focused.
+/// The mouse presses the button under it and hovering highlights it.
+///
+/// In single selection mode the group is a segmented control: the buttons are switched to
+/// [`Mode::Toggle`], and activating a button switches it on and the other buttons off.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_widgets::events::{EventHandler, Key};
+/// use ratatui_widgets::focus::Focusable;
+/// use ratatui_widgets::{Button, ButtonGroup};
+///
+/// let mut group = ButtonGroup::horizontal()
+///     .with_buttons([Button::new("Day"), Button::new("Week"), Button::new("Month")])
+///     .with_single_selection(true);
+/// group.set_focused(true);
+///
+/// group.handle_key(Key::Right.into());
+/// group.handle_key(Key::Enter.into());
+/// assert_eq!(group.take_activated(), Some(1));
+/// assert_eq!(group.selected(), Some(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ButtonGroup<'text> {
+    buttons: Vec<Button<'text>>,
+    direction: Direction,
+    spacing: u16,
+    constraint: Constraint,
+    single_selection: bool,
+    selected: Option<usize>,
+    activated: Option<usize>,
+    focus: FocusManager,
+    hit_test: HitTest,
+    gestures: GestureRecognizer,
+}
+
+impl Default for ButtonGroup<'_> {
+    fn default() -> Self {
+        Self::horizontal()
+    }
+}
+
+/// The default keymap: the arrow keys along the direction of the group move focus
+pub fn default_keymap(direction: Direction) -> Keymap<focus::Action> {
+    let (previous, next) = match direction {
+        Direction::Horizontal => (Key::Left, Key::Right),
+        Direction::Vertical => (Key::Up, Key::Down),
+    };
+    Keymap::new()
+        .with_binding([previous.into()], focus::Action::Previous)
+        .with_binding([next.into()], focus::Action::Next)
+}
+
+/// Config
+impl<'text> ButtonGroup<'text> {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            buttons: Vec::new(),
+            direction,
+            spacing: 1,
+            constraint: Constraint::Fill(1),
+            single_selection: false,
+            selected: None,
+            activated: None,
+            focus: FocusManager::new().with_keymap(default_keymap(direction)),
+            hit_test: HitTest::new(),
+            gestures: GestureRecognizer::new(),
+        }
+    }
+
+    pub fn horizontal() -> Self {
+        Self::new(Direction::Horizontal)
+    }
+
+    pub fn vertical() -> Self {
+        Self::new(Direction::Vertical)
+    }
+
+    /// Sets the gap between buttons. The default is 1.
+    pub fn with_spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the size of each button along the direction of the group. The default fills the
+    /// available space equally.
+    pub fn with_constraint(mut self, constraint: Constraint) -> Self {
+        self.constraint = constraint;
+        self
+    }
+
+    /// Sets the keys that move focus between the buttons.
+    pub fn with_keymap(mut self, keymap: Keymap<focus::Action>) -> Self {
+        self.focus = self.focus.with_keymap(keymap);
+        self
+    }
+
+    pub fn with_button(mut self, button: Button<'text>) -> Self {
+        self.push(button);
+        self
+    }
+
+    pub fn with_buttons<I: IntoIterator<Item = Button<'text>>>(mut self, buttons: I) -> Self {
+        for button in buttons {
+            self.push(button);
+        }
+        self
+    }
+
+    /// Makes the group a segmented control, where exactly one button is switched on once a
+    /// button has been activated.
+    pub fn with_single_selection(mut self, single_selection: bool) -> Self {
+        self.single_selection = single_selection;
+        if single_selection {
+            for button in &mut self.buttons {
+                button.set_mode(Mode::Toggle);
+            }
+            self.sync_toggled();
+        }
+        self
+    }
+}
+
+impl<'text> ButtonGroup<'text> {
+    pub fn push(&mut self, mut button: Button<'text>) {
+        if self.single_selection {
+            button.set_mode(Mode::Toggle);
+            button.set_toggled(false);
+        }
+        self.buttons.push(button);
+    }
+
+    pub fn buttons(&self) -> &[Button<'text>] {
+        &self.buttons
+    }
+
+    pub fn buttons_mut(&mut self) -> &mut [Button<'text>] {
+        &mut self.buttons
+    }
+
+    /// Returns the index of the focused button.
+    pub fn focused(&self) -> Option<usize> {
+        self.focus.focused()
+    }
+
+    /// Focuses the button at the given index, returning `false` if it cannot be focused.
+    pub fn focus(&mut self, index: usize) -> bool {
+        self.focus.focus(&mut self.buttons, index)
+    }
+
+    /// Returns the index of the button that is switched on in single selection mode.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Switches on the button at the given index and switches off the others, without activating
+    /// it. This only has an effect in single selection mode.
+    pub fn select(&mut self, index: usize) {
+        if self.single_selection && index < self.buttons.len() {
+            self.selected = Some(index);
+            self.sync_toggled();
+        }
+    }
+
+    /// Returns the index of the button that was most recently activated by an event, and clears
+    /// it.
+    pub fn take_activated(&mut self) -> Option<usize> {
+        self.activated.take()
+    }
+
+    fn sync_toggled(&mut self) {
+        for (index, button) in self.buttons.iter_mut().enumerate() {
+            button.set_toggled(self.selected == Some(index));
+        }
+    }
+
+    /// Handles an event and records which button, if any, it activated.
+    fn track<F: FnOnce(&mut Self)>(&mut self, handle: F) {
+        let before = self
+            .buttons
+            .iter()
+            .map(Button::activations)
+            .collect::<Vec<_>>();
+        handle(self);
+        let activated = self
+            .buttons
+            .iter()
+            .zip(before)
+            .position(|(button, before)| button.activations() != before);
+        if let Some(index) = activated {
+            self.activated = Some(index);
+            self.select(index);
+        }
+    }
+}
+
+impl EventHandler for ButtonGroup<'_> {
+    fn handle_key(&mut self, event: KeyPressedEvent) {
+        self.track(|group| {
+            let mnemonic = group
+                .buttons
+                .iter()
+                .position(|button| button.matches_mnemonic(&event));
+            match mnemonic {
+                Some(index) if group.focus(index) => group.buttons[index].handle_key(event),
+                _ => group.focus.handle_key(&mut group.buttons, event),
+            }
+        });
+    }
+
+    fn handle_key_release(&mut self, event: KeyReleasedEvent) {
+        self.focus
+            .handle_event(&mut self.buttons, Event::KeyReleased(event));
+    }
+
+    fn handle_key_repeat(&mut self, event: KeyPressedEvent) {
+        self.track(|group| {
+            group
+                .focus
+                .handle_event(&mut group.buttons, Event::KeyRepeated(event));
+        });
+    }
+
+    /// Pressing the left mouse button focuses and presses the button under the mouse. Releasing
+    /// it releases the focused button, even when the mouse has moved off the button.
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        self.track(|group| {
+            if let MouseEventKind::Down(MouseButton::Left) = event.kind {
+                if let Some(index) = group.hit_test.route(event).map(|(&index, _)| index) {
+                    group.focus(index);
+                }
+            }
+            group.hit_test.dispatch(&mut group.buttons, event);
+            group
+                .gestures
+                .dispatch(&mut group.buttons, &group.hit_test, event);
+            if let MouseEventKind::Up(MouseButton::Left) = event.kind {
+                if let Some(button) = group.focus.focused_mut(&mut group.buttons) {
+                    button.release();
+                }
+            }
+        });
+    }
+
+    fn handle_tick(&mut self) {
+        self.track(|group| {
+            for button in &mut group.buttons {
+                button.handle_tick();
+            }
+        });
+    }
+}
+
+/// The group is focused when one of its buttons is focused. Focusing the group focuses the
+/// selected button, or else the first button that can be focused.
+impl Focusable for ButtonGroup<'_> {
+    fn is_focused(&self) -> bool {
+        self.focus.focused().is_some()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if !focused {
+            self.focus.blur(&mut self.buttons);
+        } else if !self.is_focused() {
+            let selected = self.selected.is_some_and(|index| self.focus(index));
+            if !selected {
+                self.focus.focus_next(&mut self.buttons);
+            }
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        self.buttons.iter().any(Button::can_focus)
+    }
+}
+
+/// Required to be mutable to record the button areas for hit testing
+impl Widget for &mut ButtonGroup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // `iter::repeat_n` needs Rust 1.82, which is newer than the MSRV
+        #[allow(clippy::manual_repeat_n)]
+        let constraints = iter::repeat(self.constraint).take(self.buttons.len());
+        let areas = Layout::new(self.direction, constraints)
+            .spacing(self.spacing)
+            .split(area);
+        self.hit_test.clear();
+        for (index, (button, &area)) in self.buttons.iter().zip(areas.iter()).enumerate() {
+            button.render(area, buf);
+            self.hit_test.register(index, area);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::button::State;
+
+    fn group(direction: Direction) -> ButtonGroup<'static> {
+        let mut group = ButtonGroup::new(direction).with_buttons([
+            Button::new("A").with_mnemonic('a'),
+            Button::new("B").with_mnemonic('b'),
+            Button::new("C").with_mnemonic('c'),
+        ]);
+        group.set_focused(true);
+        group
+    }
+
+    fn states(group: &ButtonGroup) -> Vec<State> {
+        group.buttons().iter().map(Button::state).collect()
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16) -> MouseEvent {
+        MouseEvent {
+            column,
+            row: 0,
+            kind,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+
+    #[rstest]
+    #[case::horizontal(Direction::Horizontal, Key::Right, Key::Left)]
+    #[case::vertical(Direction::Vertical, Key::Down, Key::Up)]
+    fn roving_focus(#[case] direction: Direction, #[case] next: Key, #[case] previous: Key) {
+        let mut group = group(direction);
+        assert_eq!(group.focused(), Some(0));
+        group.handle_key(next.into());
+        assert_eq!(group.focused(), Some(1));
+        assert_eq!(
+            states(&group),
+            [State::Normal, State::Selected, State::Normal]
+        );
+        group.handle_key(previous.clone().into());
+        group.handle_key(previous.into());
+        assert_eq!(group.focused(), Some(2));
+    }
+
+    #[test]
+    fn reports_activated_button() {
+        let mut group = group(Direction::Horizontal);
+        group.handle_key(Key::Right.into());
+        assert_eq!(group.take_activated(), None);
+        group.handle_key(Key::Enter.into());
+        assert_eq!(group.take_activated(), Some(1));
+        assert_eq!(group.take_activated(), None);
+    }
+
+    #[test]
+    fn mnemonic_focuses_and_presses() {
+        let mut group = group(Direction::Horizontal);
+        group.handle_key(KeyPressedEvent {
+            key: Key::Char('c'),
+            modifiers: KeyModifiers::ALT,
+        });
+        assert_eq!(group.focused(), Some(2));
+        assert_eq!(
+            states(&group),
+            [State::Normal, State::Normal, State::Pressed]
+        );
+        assert_eq!(group.take_activated(), Some(2));
+    }
+
+    #[test]
+    fn single_selection() {
+        let mut group = group(Direction::Horizontal).with_single_selection(true);
+        group.handle_key(Key::Enter.into());
+        group.handle_key(Key::Right.into());
+        group.handle_key(Key::Enter.into());
+        assert_eq!(group.selected(), Some(1));
+        let toggled = group.buttons().iter().map(Button::is_toggled);
+        assert_eq!(toggled.collect::<Vec<_>>(), [false, true, false]);
+
+        // activating the selected button keeps it selected
+        group.handle_key(Key::Enter.into());
+        assert!(group.buttons()[1].is_toggled());
+    }
+
+    #[test]
+    fn mouse_presses_button() {
+        let mut group = group(Direction::Horizontal);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        group.render(buf.area, &mut buf);
+
+        group.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 8));
+        assert_eq!(group.focused(), Some(2));
+        assert_eq!(group.take_activated(), Some(2));
+        assert_eq!(
+            states(&group),
+            [State::Normal, State::Normal, State::Pressed]
+        );
+
+        // released outside of the button
+        group.handle_mouse(mouse(MouseEventKind::Up(MouseButton::Left), 0));
+        assert_eq!(
+            states(&group),
+            [State::Normal, State::Normal, State::Selected]
+        );
+    }
+
+    #[test]
+    fn blur() {
+        let mut group = group(Direction::Horizontal);
+        group.set_focused(false);
+        assert!(!group.is_focused());
+        assert_eq!(states(&group), [State::Normal; 3]);
+    }
+}
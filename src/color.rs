@@ -0,0 +1,275 @@
+//! Color helpers for deriving palettes and adapting colors to the terminal.
+//!
+//! Themes are written with RGB colors, which only render as intended on terminals that support
+//! true color. [`ColorDepth`] describes what the terminal supports, and [`ColorDepth::adapt`]
+//! replaces a color with the nearest one the terminal can display.
+//!
+//! Widgets check the color depth returned by [`depth`] when they render. At
+//! [`ColorDepth::Monochrome`] they show their state with modifiers and glyphs instead of colors.
+
+use std::{cell::Cell, env};
+
+use ratatui::style::{palette::tailwind::Palette, Color};
+
+/// The colors a terminal can display
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorDepth {
+    /// 24-bit RGB colors
+    #[default]
+    TrueColor,
+    /// The 256 color xterm palette
+    Indexed256,
+    /// The 16 ANSI colors
+    Ansi16,
+    /// No colors, e.g. when `NO_COLOR` is set or on a serial console
+    Monochrome,
+}
+
+thread_local! {
+    static DEPTH: Cell<Option<ColorDepth>> = const { Cell::new(None) };
+}
+
+/// Returns the color depth that widgets render for.
+///
+/// This is detected with [`ColorDepth::detect`] the first time it is needed, unless it was set
+/// with [`set_depth`].
+pub fn depth() -> ColorDepth {
+    DEPTH.get().unwrap_or_else(|| {
+        let depth = ColorDepth::detect();
+        DEPTH.set(Some(depth));
+        depth
+    })
+}
+
+/// Sets the color depth that widgets render for, from the next render on.
+///
+/// The depth is set for the current thread, which is the thread that renders.
+pub fn set_depth(depth: ColorDepth) {
+    DEPTH.set(Some(depth));
+}
+
+impl ColorDepth {
+    /// Guesses the color depth of the terminal from the `NO_COLOR`, `COLORTERM` and `TERM`
+    /// environment variables.
+    ///
+    /// A non-empty `NO_COLOR` (see <https://no-color.org>) or a `TERM` of `dumb` gives
+    /// [`ColorDepth::Monochrome`].
+    pub fn detect() -> Self {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let colorterm = env::var("COLORTERM").unwrap_or_default();
+        let term = env::var("TERM").unwrap_or_default();
+        Self::from_env(no_color, &colorterm, &term)
+    }
+
+    fn from_env(no_color: bool, colorterm: &str, term: &str) -> Self {
+        if no_color || term == "dumb" {
+            ColorDepth::Monochrome
+        } else if matches!(colorterm, "truecolor" | "24bit") || term.contains("direct") {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Indexed256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// Returns the nearest color that can be displayed at this depth.
+    ///
+    /// [`Color::Reset`] and colors that can already be displayed are returned unchanged. At
+    /// [`ColorDepth::Monochrome`] every color is replaced with [`Color::Reset`].
+    pub fn adapt(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorDepth::Monochrome, _) => Color::Reset,
+            (ColorDepth::TrueColor, _) | (_, Color::Reset) => color,
+            (ColorDepth::Indexed256, Color::Rgb(..)) => nearest_indexed(color),
+            (ColorDepth::Indexed256, _) => color,
+            (ColorDepth::Ansi16, Color::Indexed(index)) if index < 16 => color,
+            (ColorDepth::Ansi16, Color::Rgb(..) | Color::Indexed(_)) => nearest_ansi(color),
+            (ColorDepth::Ansi16, _) => color,
+        }
+    }
+}
+
+const ANSI_COLORS: [(Color, [u8; 3]); 16] = [
+    (Color::Black, [0, 0, 0]),
+    (Color::Red, [128, 0, 0]),
+    (Color::Green, [0, 128, 0]),
+    (Color::Yellow, [128, 128, 0]),
+    (Color::Blue, [0, 0, 128]),
+    (Color::Magenta, [128, 0, 128]),
+    (Color::Cyan, [0, 128, 128]),
+    (Color::Gray, [192, 192, 192]),
+    (Color::DarkGray, [128, 128, 128]),
+    (Color::LightRed, [255, 0, 0]),
+    (Color::LightGreen, [0, 255, 0]),
+    (Color::LightYellow, [255, 255, 0]),
+    (Color::LightBlue, [0, 0, 255]),
+    (Color::LightMagenta, [255, 0, 255]),
+    (Color::LightCyan, [0, 255, 255]),
+    (Color::White, [255, 255, 255]),
+];
+
+/// The levels of each channel in the 6x6x6 color cube of the 256 color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Returns the RGB value of the color, using the xterm defaults for the ANSI colors, or `None`
+/// for [`Color::Reset`].
+pub fn to_rgb(color: Color) -> Option<[u8; 3]> {
+    let rgb = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => [r, g, b],
+        Color::Indexed(index @ 0..=15) => ANSI_COLORS[usize::from(index)].1,
+        Color::Indexed(index @ 16..=231) => {
+            let index = index - 16;
+            [index / 36, index / 6 % 6, index % 6].map(|level| CUBE_LEVELS[usize::from(level)])
+        }
+        Color::Indexed(index) => [8 + 10 * (index - 232); 3],
+        named => ANSI_COLORS.iter().find(|(c, _)| *c == named)?.1,
+    };
+    Some(rgb)
+}
+
+/// Mixes two colors, returning `from` when `amount` is 0 and `to` when it is 1. Returns `from`
+/// unchanged if either color has no RGB value.
+pub fn mix(from: Color, to: Color, amount: f32) -> Color {
+    let (Some(from_rgb), Some(to_rgb)) = (to_rgb(from), to_rgb(to)) else {
+        return from;
+    };
+    let amount = amount.clamp(0.0, 1.0);
+    let channel = |i: usize| {
+        let (from, to) = (f32::from(from_rgb[i]), f32::from(to_rgb[i]));
+        (from + (to - from) * amount).round() as u8
+    };
+    Color::Rgb(channel(0), channel(1), channel(2))
+}
+
+/// Builds a palette with the base color as the 500 shade, mixing in white for the lighter shades
+/// and black for the darker ones, roughly following the tailwind palettes.
+///
+/// Returns `None` for [`Color::Reset`].
+pub fn palette(base: Color) -> Option<Palette> {
+    to_rgb(base)?;
+    let white = Color::Rgb(255, 255, 255);
+    let black = Color::Rgb(0, 0, 0);
+    Some(Palette {
+        c50: mix(base, white, 0.95),
+        c100: mix(base, white, 0.9),
+        c200: mix(base, white, 0.75),
+        c300: mix(base, white, 0.6),
+        c400: mix(base, white, 0.3),
+        c500: mix(base, white, 0.0),
+        c600: mix(base, black, 0.2),
+        c700: mix(base, black, 0.4),
+        c800: mix(base, black, 0.55),
+        c900: mix(base, black, 0.7),
+        c950: mix(base, black, 0.85),
+    })
+}
+
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b)
+        .map(|(&a, b)| u32::from(a.abs_diff(b)).pow(2))
+        .sum()
+}
+
+/// Returns the nearest color of the 256 color palette, excluding the 16 ANSI colors, which
+/// terminals often customize.
+fn nearest_indexed(color: Color) -> Color {
+    let Some(rgb) = to_rgb(color) else {
+        return color;
+    };
+    let nearest_level = |channel: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&level| CUBE_LEVELS[level].abs_diff(channel))
+            .unwrap_or_default() as u8
+    };
+    let [r, g, b] = rgb.map(nearest_level);
+    let cube = 16 + 36 * r + 6 * g + b;
+
+    let average = rgb.iter().map(|&c| u16::from(c)).sum::<u16>() / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    [cube, gray]
+        .into_iter()
+        .min_by_key(|&index| to_rgb(Color::Indexed(index)).map_or(u32::MAX, |c| distance(c, rgb)))
+        .map_or(color, Color::Indexed)
+}
+
+/// Returns the nearest of the 16 ANSI colors.
+fn nearest_ansi(color: Color) -> Color {
+    let Some(rgb) = to_rgb(color) else {
+        return color;
+    };
+    ANSI_COLORS
+        .iter()
+        .min_by_key(|(_, ansi)| distance(*ansi, rgb))
+        .map_or(color, |(ansi, _)| *ansi)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::rgb(Color::Rgb(1, 2, 3), Some([1, 2, 3]))]
+    #[case::named(Color::LightBlue, Some([0, 0, 255]))]
+    #[case::indexed_ansi(Color::Indexed(1), Some([128, 0, 0]))]
+    #[case::indexed_cube(Color::Indexed(16 + 36 * 5 + 6 * 2), Some([255, 135, 0]))]
+    #[case::indexed_gray(Color::Indexed(255), Some([238, 238, 238]))]
+    #[case::reset(Color::Reset, None)]
+    fn rgb(#[case] color: Color, #[case] expected: Option<[u8; 3]>) {
+        assert_eq!(to_rgb(color), expected);
+    }
+
+    #[rstest]
+    #[case::true_color(
+        ColorDepth::TrueColor,
+        Color::Rgb(250, 130, 10),
+        Color::Rgb(250, 130, 10)
+    )]
+    #[case::cube(ColorDepth::Indexed256, Color::Rgb(250, 130, 10), Color::Indexed(208))]
+    #[case::gray(ColorDepth::Indexed256, Color::Rgb(100, 101, 99), Color::Indexed(241))]
+    #[case::named_256(ColorDepth::Indexed256, Color::Red, Color::Red)]
+    #[case::ansi(ColorDepth::Ansi16, Color::Rgb(250, 10, 10), Color::LightRed)]
+    #[case::indexed_ansi(ColorDepth::Ansi16, Color::Indexed(17), Color::Blue)]
+    #[case::reset(ColorDepth::Ansi16, Color::Reset, Color::Reset)]
+    #[case::monochrome(ColorDepth::Monochrome, Color::LightRed, Color::Reset)]
+    fn adapt(#[case] depth: ColorDepth, #[case] color: Color, #[case] expected: Color) {
+        assert_eq!(depth.adapt(color), expected);
+    }
+
+    #[rstest]
+    #[case::truecolor(false, "truecolor", "xterm", ColorDepth::TrueColor)]
+    #[case::direct(false, "", "xterm-direct", ColorDepth::TrueColor)]
+    #[case::indexed(false, "", "xterm-256color", ColorDepth::Indexed256)]
+    #[case::ansi(false, "", "linux", ColorDepth::Ansi16)]
+    #[case::dumb(false, "", "dumb", ColorDepth::Monochrome)]
+    #[case::no_color(true, "truecolor", "xterm-256color", ColorDepth::Monochrome)]
+    fn from_env(
+        #[case] no_color: bool,
+        #[case] colorterm: &str,
+        #[case] term: &str,
+        #[case] expected: ColorDepth,
+    ) {
+        assert_eq!(ColorDepth::from_env(no_color, colorterm, term), expected);
+    }
+
+    #[test]
+    fn set_depth() {
+        super::set_depth(ColorDepth::Monochrome);
+        assert_eq!(depth(), ColorDepth::Monochrome);
+    }
+
+    #[test]
+    fn palette_shades() {
+        let palette = palette(Color::Rgb(200, 100, 0)).unwrap();
+        assert_eq!(palette.c500, Color::Rgb(200, 100, 0));
+        assert_eq!(palette.c400, Color::Rgb(217, 147, 77));
+        assert_eq!(palette.c900, Color::Rgb(60, 30, 0));
+        assert!(super::palette(Color::Reset).is_none());
+    }
+}
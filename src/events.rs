@@ -7,7 +7,14 @@
 //! are difficult to handle in a generic way.
 
 use bitflags::bitflags;
-use strum::EnumIs;
+use strum::{Display, EnumIs, EnumIter, EnumString};
+use thiserror::Error;
+
+#[cfg(feature = "theme-file")]
+use crate::theme::file::ThemeFile;
+
+mod chord;
+pub use chord::ParseKeyError;
 
 #[cfg(feature = "crossterm")]
 mod crossterm;
@@ -17,33 +24,237 @@ mod termion;
 
 #[cfg(feature = "termwiz")]
 mod termwiz;
+#[cfg(feature = "termwiz")]
+pub use self::termwiz::TermwizConverter;
+
+#[cfg(feature = "tokio")]
+mod stream;
+#[cfg(feature = "tokio")]
+pub use stream::{EventStream, Timers};
+
+/// An error converting a backend event into an [`Event`].
+///
+/// The same error type is used by all backends. Each backend provides a `TryFrom` implementation
+/// that returns this error, and a `from_<backend>` method (e.g. `Event::from_crossterm`) that
+/// returns `Ok(None)` for the unsupported events that are safe to ignore and only returns the
+/// errors that indicate a real problem, which is usually what an application wants.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+pub enum ConversionError {
+    /// The backend event has no equivalent [`Event`]. These events are safe to ignore.
+    #[error("Unsupported {backend} event: {event}")]
+    UnsupportedEvent {
+        backend: &'static str,
+        event: String,
+    },
+    /// The backend key has no equivalent [`Key`]. These events are safe to ignore.
+    #[error("Unsupported {backend} key: {key}")]
+    UnsupportedKey { backend: &'static str, key: String },
+    /// The backend event has modifiers that have no equivalent in [`KeyModifiers`]. These events
+    /// are safe to ignore. They are not converted without the unknown modifiers, as that would
+    /// turn e.g. an unknown modifier and `q` into a plain `q`.
+    #[error("Unknown {backend} modifiers: {modifiers}")]
+    UnknownModifiers {
+        backend: &'static str,
+        modifiers: String,
+    },
+    /// The backend produced an event that could not be interpreted.
+    #[error("Invalid {backend} event: {event}")]
+    InvalidEvent {
+        backend: &'static str,
+        event: String,
+    },
+}
+
+impl ConversionError {
+    /// Returns true if the event has no equivalent [`Event`] and can be safely ignored.
+    pub fn is_unsupported(&self) -> bool {
+        matches!(
+            self,
+            ConversionError::UnsupportedEvent { .. }
+                | ConversionError::UnsupportedKey { .. }
+                | ConversionError::UnknownModifiers { .. }
+        )
+    }
+}
+
+/// Turns the unsupported errors of a conversion into `None`, keeping the errors that indicate a
+/// real problem.
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+fn skip_unsupported(
+    result: Result<Event, ConversionError>,
+) -> Result<Option<Event>, ConversionError> {
+    match result {
+        Ok(event) => Ok(Some(event)),
+        Err(error) if error.is_unsupported() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
 
 pub trait EventHandler {
     fn handle_event(&mut self, event: Event) {
         match event {
             Event::KeyPressed(key_pressed_event) => self.handle_key(key_pressed_event),
+            Event::KeyReleased(key_released_event) => self.handle_key_release(key_released_event),
+            Event::KeyRepeated(key_pressed_event) => self.handle_key_repeat(key_pressed_event),
             Event::Mouse(mouse_event) => self.handle_mouse(mouse_event),
+            Event::Paste(text) => self.handle_paste(text),
+            Event::Resize { columns, rows } => self.handle_resize(columns, rows),
+            Event::FocusGained => self.handle_focus_change(true),
+            Event::FocusLost => self.handle_focus_change(false),
+            Event::Tick => self.handle_tick(),
+            Event::Timer(id) => self.handle_timer(id),
+            #[cfg(feature = "theme-file")]
+            Event::ThemeChanged(theme) => self.handle_theme_change(theme),
         }
     }
 
     #[allow(unused_variables)]
     fn handle_key(&mut self, event: KeyPressedEvent) {}
 
+    /// Key releases are only reported by terminals that support keyboard enhancement (e.g. the
+    /// kitty keyboard protocol).
+    #[allow(unused_variables)]
+    fn handle_key_release(&mut self, event: KeyReleasedEvent) {}
+
+    /// Key repeats are only reported by terminals that support keyboard enhancement. Other
+    /// terminals report a repeated key as another key press, so by default a repeat is handled
+    /// the same way.
+    fn handle_key_repeat(&mut self, event: KeyPressedEvent) {
+        self.handle_key(event);
+    }
+
     #[allow(unused_variables)]
     fn handle_mouse(&mut self, event: MouseEvent) {}
+
+    /// Pastes are only reported as a single event when bracketed paste is enabled. Otherwise the
+    /// pasted text arrives as individual key presses.
+    #[allow(unused_variables)]
+    fn handle_paste(&mut self, text: String) {}
+
+    #[allow(unused_variables)]
+    fn handle_resize(&mut self, columns: u16, rows: u16) {}
+
+    /// Called when the terminal window gains (`focused == true`) or loses focus. This requires
+    /// focus change reporting to be enabled in the terminal.
+    #[allow(unused_variables)]
+    fn handle_focus_change(&mut self, focused: bool) {}
+
+    /// Called at a regular rate by event sources that produce ticks, such as the `EventStream`
+    /// provided by the `tokio` feature.
+    fn handle_tick(&mut self) {}
+
+    /// Called when a timer registered with an event source (e.g. through `Timers`) fires.
+    #[allow(unused_variables)]
+    fn handle_timer(&mut self, id: TimerId) {}
+
+    /// Called when a watched theme file has been reloaded, with the widget themes read from it.
+    #[cfg(feature = "theme-file")]
+    #[allow(unused_variables)]
+    fn handle_theme_change(&mut self, theme: ThemeFile) {}
+}
+
+impl<T: EventHandler + ?Sized> EventHandler for &mut T {
+    fn handle_event(&mut self, event: Event) {
+        (**self).handle_event(event);
+    }
+
+    fn handle_key(&mut self, event: KeyPressedEvent) {
+        (**self).handle_key(event);
+    }
+
+    fn handle_key_release(&mut self, event: KeyReleasedEvent) {
+        (**self).handle_key_release(event);
+    }
+
+    fn handle_key_repeat(&mut self, event: KeyPressedEvent) {
+        (**self).handle_key_repeat(event);
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        (**self).handle_mouse(event);
+    }
+
+    fn handle_paste(&mut self, text: String) {
+        (**self).handle_paste(text);
+    }
+
+    fn handle_resize(&mut self, columns: u16, rows: u16) {
+        (**self).handle_resize(columns, rows);
+    }
+
+    fn handle_focus_change(&mut self, focused: bool) {
+        (**self).handle_focus_change(focused);
+    }
+
+    fn handle_tick(&mut self) {
+        (**self).handle_tick();
+    }
+
+    fn handle_timer(&mut self, id: TimerId) {
+        (**self).handle_timer(id);
+    }
+
+    #[cfg(feature = "theme-file")]
+    fn handle_theme_change(&mut self, theme: ThemeFile) {
+        (**self).handle_theme_change(theme);
+    }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     KeyPressed(KeyPressedEvent),
+    KeyReleased(KeyReleasedEvent),
+    KeyRepeated(KeyPressedEvent),
     Mouse(MouseEvent),
+    /// Text pasted into the terminal with bracketed paste enabled
+    Paste(String),
+    /// The terminal was resized to the given size
+    Resize {
+        columns: u16,
+        rows: u16,
+    },
+    /// The terminal window gained focus
+    FocusGained,
+    /// The terminal window lost focus
+    FocusLost,
+    /// A regular tick, used to drive animations and other time based behavior
+    Tick,
+    /// A timer fired
+    Timer(TimerId),
+    /// A watched theme file was reloaded, see [`runner::watch_theme`]
+    ///
+    /// [`runner::watch_theme`]: crate::runner::watch_theme
+    #[cfg(feature = "theme-file")]
+    ThemeChanged(ThemeFile),
 }
 
+/// Identifies a timer registered with an event source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerId(pub u64);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KeyPressedEvent {
     pub key: Key,
     pub modifiers: KeyModifiers,
 }
 
+impl From<Key> for KeyPressedEvent {
+    fn from(key: Key) -> Self {
+        KeyPressedEvent {
+            key,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyReleasedEvent {
+    pub key: Key,
+    pub modifiers: KeyModifiers,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIs)]
 pub enum Key {
     Char(char),
@@ -64,6 +275,112 @@ pub enum Key {
     BackTab,
     F(u8),
     Null,
+    CapsLock,
+    ScrollLock,
+    NumLock,
+    PrintScreen,
+    Pause,
+    /// The context menu key (also known as the application key)
+    Menu,
+    /// A key on the numeric keypad
+    ///
+    /// Most terminals report keypad keys as their regular equivalents, so these are only seen on
+    /// terminals and backends that distinguish them.
+    Keypad(KeypadKey),
+    /// A media key
+    Media(MediaKey),
+    /// A modifier key pressed on its own
+    ///
+    /// These are only reported by terminals that support keyboard enhancement (e.g. the kitty
+    /// keyboard protocol).
+    Modifier(ModifierKey),
+    /// A browser navigation key
+    Browser(BrowserKey),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeypadKey {
+    Digit0,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+    Multiply,
+    Add,
+    Separator,
+    Subtract,
+    Decimal,
+    Divide,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    /// The center key (5 when num lock is off)
+    Begin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+#[strum(ascii_case_insensitive)]
+pub enum MediaKey {
+    Play,
+    Pause,
+    PlayPause,
+    Reverse,
+    Stop,
+    FastForward,
+    Rewind,
+    TrackNext,
+    TrackPrevious,
+    Record,
+    LowerVolume,
+    RaiseVolume,
+    MuteVolume,
+}
+
+/// A modifier key. Some backends report which side of the keyboard the key is on, others don't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+#[strum(ascii_case_insensitive)]
+pub enum ModifierKey {
+    Shift,
+    #[strum(to_string = "Control", serialize = "Ctrl")]
+    Control,
+    #[strum(to_string = "Alt", serialize = "Option")]
+    Alt,
+    #[strum(to_string = "Super", serialize = "Win", serialize = "Command")]
+    Super,
+    Hyper,
+    Meta,
+    LeftShift,
+    LeftControl,
+    LeftAlt,
+    LeftSuper,
+    LeftHyper,
+    LeftMeta,
+    RightShift,
+    RightControl,
+    RightAlt,
+    RightSuper,
+    RightHyper,
+    RightMeta,
+    IsoLevel3Shift,
+    IsoLevel5Shift,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+#[strum(ascii_case_insensitive)]
+pub enum BrowserKey {
+    Back,
+    Forward,
+    Refresh,
+    Stop,
+    Search,
+    Favorites,
+    Home,
 }
 
 bitflags! {
@@ -93,6 +410,7 @@ bitflags! {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MouseEvent {
     pub column: u16,
     pub row: u16,
@@ -101,6 +419,7 @@ pub struct MouseEvent {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseEventKind {
     Down(MouseButton),
     Up(MouseButton),
@@ -113,6 +432,7 @@ pub enum MouseEventKind {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseButton {
     Left,
     Right,
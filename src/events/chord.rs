@@ -0,0 +1,449 @@
+//! Parsing and display of key chords such as `"ctrl+shift+p"`, `"alt+enter"` or `"F5"`.
+//!
+//! Modifier and key names are case insensitive, but character keys are case sensitive, so
+//! `"ctrl+P"` and `"ctrl+p"` are different chords. Space and plus are written as `Space` and
+//! `Plus`, though a trailing `+` (e.g. `"ctrl++"`) is also accepted.
+
+use std::{fmt, str::FromStr};
+
+use itertools::Itertools;
+use thiserror::Error;
+
+use super::{Key, KeyModifiers, KeyPressedEvent, KeyReleasedEvent, KeypadKey};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+pub enum ParseKeyError {
+    #[error("Empty key")]
+    Empty,
+    #[error("Unknown key: {key}")]
+    UnknownKey { key: String },
+    #[error("Unknown modifier: {modifier}")]
+    UnknownModifier { modifier: String },
+}
+
+/// The canonical modifier names, in the order they are displayed
+const MODIFIER_NAMES: [(KeyModifiers, &str); 6] = [
+    (KeyModifiers::CTRL, "Ctrl"),
+    (KeyModifiers::ALT, "Alt"),
+    (KeyModifiers::SHIFT, "Shift"),
+    (KeyModifiers::SUPER, "Super"),
+    (KeyModifiers::HYPER, "Hyper"),
+    (KeyModifiers::META, "Meta"),
+];
+
+/// Implements `Display` with an exhaustive `match`, where the variants without data are written as
+/// their canonical name, and defines the table of those names that the parser looks up. Naming the
+/// variants in one place means a new variant is a compile error until it is given a name, rather
+/// than a panic when it is displayed.
+macro_rules! display_with_names {
+    (
+        $(#[$meta:meta])*
+        const $table:ident: $ty:ident {
+            $($variant:ident => $name:literal,)*
+        }
+        fn fmt(&self, $f:ident) {
+            $($pattern:pat => $body:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        const $table: [($ty, &str); [$($name),*].len()] = [$(($ty::$variant, $name)),*];
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, $f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $($ty::$variant => $f.write_str($name),)*
+                    $($pattern => $body,)*
+                }
+            }
+        }
+    };
+}
+
+display_with_names! {
+    /// The canonical names of the keys that have no data
+    const KEY_NAMES: Key {
+        Backspace => "Backspace",
+        Delete => "Delete",
+        Insert => "Insert",
+        Enter => "Enter",
+        Left => "Left",
+        Right => "Right",
+        Up => "Up",
+        Down => "Down",
+        Esc => "Esc",
+        Home => "Home",
+        End => "End",
+        PageUp => "PageUp",
+        PageDown => "PageDown",
+        Tab => "Tab",
+        BackTab => "BackTab",
+        Null => "Null",
+        CapsLock => "CapsLock",
+        ScrollLock => "ScrollLock",
+        NumLock => "NumLock",
+        PrintScreen => "PrintScreen",
+        Pause => "Pause",
+        Menu => "Menu",
+    }
+    fn fmt(&self, f) {
+        Key::Char(' ') => f.write_str("Space"),
+        Key::Char('+') => f.write_str("Plus"),
+        Key::Char(c) => write!(f, "{c}"),
+        Key::F(n) => write!(f, "F{n}"),
+        Key::Keypad(key) => write!(f, "Keypad{key}"),
+        Key::Media(key) => write!(f, "Media{key}"),
+        Key::Modifier(key) => write!(f, "{key}"),
+        Key::Browser(key) => write!(f, "Browser{key}"),
+    }
+}
+
+/// Alternative names accepted when parsing keys
+const KEY_ALIASES: [(Key, &str); 8] = [
+    (Key::Char(' '), "Space"),
+    (Key::Char('+'), "Plus"),
+    (Key::Esc, "Escape"),
+    (Key::Enter, "Return"),
+    (Key::Delete, "Del"),
+    (Key::Insert, "Ins"),
+    (Key::PageUp, "PgUp"),
+    (Key::PageDown, "PgDn"),
+];
+
+display_with_names! {
+    /// The canonical names of the keypad keys that have no data
+    const KEYPAD_KEY_NAMES: KeypadKey {
+        Digit0 => "0",
+        Digit1 => "1",
+        Digit2 => "2",
+        Digit3 => "3",
+        Digit4 => "4",
+        Digit5 => "5",
+        Digit6 => "6",
+        Digit7 => "7",
+        Digit8 => "8",
+        Digit9 => "9",
+        Multiply => "Multiply",
+        Add => "Add",
+        Separator => "Separator",
+        Subtract => "Subtract",
+        Decimal => "Decimal",
+        Divide => "Divide",
+        Home => "Home",
+        End => "End",
+        PageUp => "PageUp",
+        PageDown => "PageDown",
+        Begin => "Begin",
+    }
+    fn fmt(&self, f) {}
+}
+
+impl fmt::Display for KeyModifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = MODIFIER_NAMES
+            .iter()
+            .filter(|(modifier, _)| self.contains(*modifier))
+            .map(|(_, name)| name);
+        write!(f, "{}", names.format("+"))
+    }
+}
+
+/// Parses `+` separated modifiers, e.g. `"ctrl+shift"`. `CONTROL`, `OPTION`, `COMMAND` and `WIN`
+/// are accepted as aliases for `CTRL`, `ALT`, `SUPER` and `SUPER`.
+impl FromStr for KeyModifiers {
+    type Err = ParseKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(KeyModifiers::empty());
+        }
+        s.split('+')
+            .map(|name| parse_modifier(name.trim()))
+            .fold_ok(KeyModifiers::empty(), |acc, modifier| acc | modifier)
+    }
+}
+
+fn parse_modifier(name: &str) -> Result<KeyModifiers, ParseKeyError> {
+    let modifier = match name.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => KeyModifiers::CTRL,
+        "alt" | "option" | "opt" => KeyModifiers::ALT,
+        "shift" => KeyModifiers::SHIFT,
+        "super" | "win" | "command" | "cmd" => KeyModifiers::SUPER,
+        "hyper" => KeyModifiers::HYPER,
+        "meta" => KeyModifiers::META,
+        _ => {
+            return Err(ParseKeyError::UnknownModifier {
+                modifier: name.to_string(),
+            })
+        }
+    };
+    Ok(modifier)
+}
+
+impl FromStr for Key {
+    type Err = ParseKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => return Err(ParseKeyError::Empty),
+            (Some(c), None) => return Ok(Key::Char(c)),
+            _ => {}
+        }
+        let named_key = KEY_NAMES
+            .iter()
+            .chain(KEY_ALIASES.iter())
+            .find(|(_, name)| name.eq_ignore_ascii_case(s));
+        if let Some((key, _)) = named_key {
+            return Ok(key.clone());
+        }
+        let lower = s.to_ascii_lowercase();
+        let key = if let Some(n) = lower.strip_prefix('f').and_then(|n| n.parse().ok()) {
+            Some(Key::F(n))
+        } else if let Some(name) = lower.strip_prefix("keypad") {
+            name.parse().ok().map(Key::Keypad)
+        } else if let Some(name) = lower.strip_prefix("media") {
+            name.parse().ok().map(Key::Media)
+        } else if let Some(name) = lower.strip_prefix("browser") {
+            name.parse().ok().map(Key::Browser)
+        } else {
+            lower.parse().ok().map(Key::Modifier)
+        };
+        key.ok_or_else(|| ParseKeyError::UnknownKey { key: s.to_string() })
+    }
+}
+
+impl FromStr for KeypadKey {
+    type Err = ParseKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KEYPAD_KEY_NAMES
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(s))
+            .map(|(key, _)| *key)
+            .ok_or_else(|| ParseKeyError::UnknownKey { key: s.to_string() })
+    }
+}
+
+impl fmt::Display for KeyPressedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_chord(&self.key, self.modifiers, f)
+    }
+}
+
+/// Parses a key chord such as `"ctrl+shift+p"`, `"alt+enter"` or `"F5"`.
+impl FromStr for KeyPressedEvent {
+    type Err = ParseKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, modifiers) = parse_chord(s)?;
+        Ok(KeyPressedEvent { key, modifiers })
+    }
+}
+
+/// Formats the released key as a chord, the same as [`KeyPressedEvent`].
+impl fmt::Display for KeyReleasedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_chord(&self.key, self.modifiers, f)
+    }
+}
+
+/// Parses a key chord, the same as [`KeyPressedEvent`].
+impl FromStr for KeyReleasedEvent {
+    type Err = ParseKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, modifiers) = parse_chord(s)?;
+        Ok(KeyReleasedEvent { key, modifiers })
+    }
+}
+
+fn fmt_chord(key: &Key, modifiers: KeyModifiers, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if modifiers.is_empty() {
+        write!(f, "{key}")
+    } else {
+        write!(f, "{modifiers}+{key}")
+    }
+}
+
+fn parse_chord(s: &str) -> Result<(Key, KeyModifiers), ParseKeyError> {
+    let s = s.trim();
+    let (modifiers, key) = if s == "+" {
+        ("", s)
+    } else if let Some(modifiers) = s.strip_suffix("++") {
+        (modifiers, "+")
+    } else {
+        s.rsplit_once('+').unwrap_or(("", s))
+    };
+    Ok((key.trim().parse()?, modifiers.parse()?))
+}
+
+/// Serializes the types that have a chord form as strings, e.g. `"Ctrl+Shift+p"`.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_via_str {
+    ($($ty:ty),*) => {
+        $(
+            impl serde::Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                    s.parse().map_err(serde::de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_serde_via_str!(Key, KeyModifiers, KeyPressedEvent, KeyReleasedEvent);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{BrowserKey, MediaKey, ModifierKey};
+    use rstest::rstest;
+    use strum::IntoEnumIterator;
+
+    fn all_keys() -> Vec<Key> {
+        let chars = ['a', 'Z', '1', ' ', '+', '-', 'é'].map(Key::Char);
+        let named = KEY_NAMES.iter().map(|(key, _)| key.clone());
+        let function = [1, 12, 24].map(Key::F);
+        let keypad = KEYPAD_KEY_NAMES.iter().map(|(key, _)| Key::Keypad(*key));
+        let media = MediaKey::iter().map(Key::Media);
+        let modifier = ModifierKey::iter().map(Key::Modifier);
+        let browser = BrowserKey::iter().map(Key::Browser);
+        chars
+            .into_iter()
+            .chain(named)
+            .chain(function)
+            .chain(keypad)
+            .chain(media)
+            .chain(modifier)
+            .chain(browser)
+            .collect()
+    }
+
+    #[test]
+    fn key_round_trip() {
+        for key in all_keys() {
+            assert_eq!(key.to_string().parse(), Ok(key.clone()), "{key}");
+        }
+    }
+
+    #[test]
+    fn key_pressed_event_round_trip() {
+        let modifiers = [
+            KeyModifiers::empty(),
+            KeyModifiers::CTRL,
+            KeyModifiers::CTRL | KeyModifiers::SHIFT,
+            KeyModifiers::all(),
+        ];
+        for (key, modifiers) in all_keys().into_iter().cartesian_product(modifiers) {
+            let event = KeyPressedEvent { key, modifiers };
+            assert_eq!(event.to_string().parse(), Ok(event.clone()), "{event}");
+        }
+    }
+
+    #[rstest]
+    #[case(KeyModifiers::empty(), "")]
+    #[case(KeyModifiers::CONTROL, "Ctrl")]
+    #[case(KeyModifiers::SHIFT | KeyModifiers::CTRL, "Ctrl+Shift")]
+    #[case(KeyModifiers::all(), "Ctrl+Alt+Shift+Super+Hyper+Meta")]
+    fn display_modifiers(#[case] modifiers: KeyModifiers, #[case] expected: &str) {
+        assert_eq!(modifiers.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("", KeyModifiers::empty())]
+    #[case("ctrl", KeyModifiers::CTRL)]
+    #[case("CONTROL", KeyModifiers::CTRL)]
+    #[case("Option", KeyModifiers::ALT)]
+    #[case("command", KeyModifiers::SUPER)]
+    #[case("WIN", KeyModifiers::SUPER)]
+    #[case("ctrl + shift", KeyModifiers::CTRL | KeyModifiers::SHIFT)]
+    fn parse_modifiers(#[case] s: &str, #[case] expected: KeyModifiers) {
+        assert_eq!(s.parse(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(Key::Char('p'), "p")]
+    #[case(Key::Char(' '), "Space")]
+    #[case(Key::F(5), "F5")]
+    #[case(Key::PageDown, "PageDown")]
+    #[case(Key::Keypad(KeypadKey::Digit7), "Keypad7")]
+    #[case(Key::Keypad(KeypadKey::Begin), "KeypadBegin")]
+    #[case(Key::Media(MediaKey::PlayPause), "MediaPlayPause")]
+    #[case(Key::Modifier(ModifierKey::LeftShift), "LeftShift")]
+    #[case(Key::Browser(BrowserKey::Back), "BrowserBack")]
+    fn display_key(#[case] key: Key, #[case] expected: &str) {
+        assert_eq!(key.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("ctrl+shift+p", Key::Char('p'), KeyModifiers::CTRL | KeyModifiers::SHIFT)]
+    #[case("alt+enter", Key::Enter, KeyModifiers::ALT)]
+    #[case("F5", Key::F(5), KeyModifiers::empty())]
+    #[case("f12", Key::F(12), KeyModifiers::empty())]
+    #[case("esc", Key::Esc, KeyModifiers::empty())]
+    #[case("Escape", Key::Esc, KeyModifiers::empty())]
+    #[case("control+P", Key::Char('P'), KeyModifiers::CTRL)]
+    #[case("command+s", Key::Char('s'), KeyModifiers::SUPER)]
+    #[case("ctrl+space", Key::Char(' '), KeyModifiers::CTRL)]
+    #[case("ctrl++", Key::Char('+'), KeyModifiers::CTRL)]
+    #[case("+", Key::Char('+'), KeyModifiers::empty())]
+    #[case("f", Key::Char('f'), KeyModifiers::empty())]
+    #[case("ctrl+alt", Key::Modifier(ModifierKey::Alt), KeyModifiers::CTRL)]
+    #[case("ctrl+ctrl", Key::Modifier(ModifierKey::Control), KeyModifiers::CTRL)]
+    #[case("mediapause", Key::Media(MediaKey::Pause), KeyModifiers::empty())]
+    fn parse_key_pressed_event(#[case] s: &str, #[case] key: Key, #[case] modifiers: KeyModifiers) {
+        assert_eq!(s.parse(), Ok(KeyPressedEvent { key, modifiers }));
+    }
+
+    #[rstest]
+    #[case("", ParseKeyError::Empty)]
+    #[case("ctrl+", ParseKeyError::Empty)]
+    #[case("nope", ParseKeyError::UnknownKey { key: "nope".into() })]
+    #[case("Keypad10", ParseKeyError::UnknownKey { key: "Keypad10".into() })]
+    #[case("hyperx+a", ParseKeyError::UnknownModifier { modifier: "hyperx".into() })]
+    fn parse_key_pressed_event_error(#[case] s: &str, #[case] expected: ParseKeyError) {
+        assert_eq!(s.parse::<KeyPressedEvent>(), Err(expected));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_uses_chord_form() {
+        let event = KeyPressedEvent {
+            key: Key::Char('p'),
+            modifiers: KeyModifiers::CTRL | KeyModifiers::SHIFT,
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(json, r#""Ctrl+Shift+p""#);
+        assert_eq!(
+            serde_json::from_str::<KeyPressedEvent>(&json).unwrap(),
+            event
+        );
+
+        let released = KeyReleasedEvent {
+            key: event.key.clone(),
+            modifiers: event.modifiers,
+        };
+        assert_eq!(serde_json::to_string(&released).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<KeyReleasedEvent>(&json).unwrap(),
+            released
+        );
+
+        assert_eq!(serde_json::to_string(&Key::F(5)).unwrap(), r#""F5""#);
+        assert_eq!(
+            serde_json::from_str::<KeyModifiers>(r#""control+option""#).unwrap(),
+            KeyModifiers::CTRL | KeyModifiers::ALT
+        );
+        let error = serde_json::from_str::<Key>(r#""nope""#).unwrap_err();
+        assert!(error.to_string().contains("Unknown key: nope"), "{error}");
+    }
+}
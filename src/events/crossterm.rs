@@ -1,24 +1,25 @@
 use crossterm::event::{
     Event as CrosstermEvent, KeyCode as CrosstermKeyCode, KeyEvent as CrosstermKeyEvent,
-    KeyEventKind, KeyModifiers as CrosstermKeyModifiers, MouseEvent as CrosstermMouseEvent,
+    KeyEventKind, KeyModifiers as CrosstermKeyModifiers, MediaKeyCode as CrosstermMediaKeyCode,
+    ModifierKeyCode as CrosstermModifierKeyCode, MouseEvent as CrosstermMouseEvent,
 };
-use thiserror::Error;
 
 use super::MouseEventKind;
-use super::{Event, Key, KeyModifiers, KeyPressedEvent, MouseButton, MouseEvent};
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
-pub enum ConversionError {
-    #[error("Unknown crossterm event: {event:?}")]
-    UnknownEvent { event: CrosstermEvent },
-    #[error("Unknown crossterm key code: {key_code:?}")]
-    UnknownKey { key_code: CrosstermKeyCode },
-    #[error("Unknown crossterm modifiers: {modifiers:?}")]
-    UnknownModifiers { modifiers: u8 },
-    #[error("Key repeated event not supported")]
-    KeyReleasedEventNotSupported,
-    #[error("Key repeated event not supported")]
-    KeyRepeatedEventNotSupported,
+use super::{
+    ConversionError, Event, Key, KeyModifiers, KeyPressedEvent, KeyReleasedEvent, KeypadKey,
+    MediaKey, ModifierKey, MouseButton, MouseEvent,
+};
+
+const BACKEND: &str = "crossterm";
+
+impl Event {
+    /// Converts a crossterm event, returning `Ok(None)` if the event has no equivalent.
+    ///
+    /// Every crossterm event currently has an equivalent, except for key and mouse events with
+    /// modifier bits that crossterm has no name for.
+    pub fn from_crossterm(event: CrosstermEvent) -> Result<Option<Event>, ConversionError> {
+        super::skip_unsupported(Event::try_from(event))
+    }
 }
 
 impl TryFrom<CrosstermEvent> for Event {
@@ -26,38 +27,49 @@ impl TryFrom<CrosstermEvent> for Event {
     fn try_from(event: CrosstermEvent) -> Result<Self, Self::Error> {
         use CrosstermEvent::*;
         let event = match event {
-            Key(key_event) => Event::KeyPressed(key_event.try_into()?),
-            Mouse(mouse_event) => Event::Mouse(mouse_event.into()),
-            _ => return Err(ConversionError::UnknownEvent { event }),
-            // TODO maybe handle these later if needed
-            // FocusGained => todo!(),
-            // FocusLost => todo!(),
-            // Paste(_) => todo!(),
-            // Resize(_, _) => todo!(),
+            Key(key_event) => match key_event.kind {
+                KeyEventKind::Press => Event::KeyPressed(key_event.try_into()?),
+                KeyEventKind::Release => Event::KeyReleased(key_event.try_into()?),
+                KeyEventKind::Repeat => Event::KeyRepeated(key_event.try_into()?),
+            },
+            Mouse(mouse_event) => Event::Mouse(mouse_event.try_into()?),
+            Paste(text) => Event::Paste(text),
+            Resize(columns, rows) => Event::Resize { columns, rows },
+            FocusGained => Event::FocusGained,
+            FocusLost => Event::FocusLost,
         };
         Ok(event)
     }
 }
 
+/// Converts the key and modifiers of the event. The kind of the event is ignored, as it is
+/// represented by the [`Event`] variant instead.
 impl TryFrom<CrosstermKeyEvent> for KeyPressedEvent {
     type Error = ConversionError;
     fn try_from(key_event: CrosstermKeyEvent) -> Result<Self, Self::Error> {
-        match key_event.kind {
-            KeyEventKind::Press => Ok(KeyPressedEvent {
-                key: key_event.code.try_into()?,
-                modifiers: key_event.modifiers.into(),
-            }),
-            KeyEventKind::Release => Err(ConversionError::KeyReleasedEventNotSupported),
-            KeyEventKind::Repeat => Err(ConversionError::KeyRepeatedEventNotSupported),
-        }
+        Ok(KeyPressedEvent {
+            key: key_event.code.into(),
+            modifiers: key_event.modifiers.try_into()?,
+        })
     }
 }
 
-impl TryFrom<CrosstermKeyCode> for Key {
+/// Converts the key and modifiers of the event. The kind of the event is ignored, as it is
+/// represented by the [`Event`] variant instead.
+impl TryFrom<CrosstermKeyEvent> for KeyReleasedEvent {
     type Error = ConversionError;
-    fn try_from(key_code: CrosstermKeyCode) -> Result<Self, Self::Error> {
+    fn try_from(key_event: CrosstermKeyEvent) -> Result<Self, Self::Error> {
+        Ok(KeyReleasedEvent {
+            key: key_event.code.into(),
+            modifiers: key_event.modifiers.try_into()?,
+        })
+    }
+}
+
+impl From<CrosstermKeyCode> for Key {
+    fn from(key_code: CrosstermKeyCode) -> Self {
         use Key::*;
-        let key = match key_code {
+        match key_code {
             CrosstermKeyCode::Char(c) => Char(c),
             CrosstermKeyCode::Esc => Esc,
             CrosstermKeyCode::Enter => Enter,
@@ -76,39 +88,83 @@ impl TryFrom<CrosstermKeyCode> for Key {
             CrosstermKeyCode::PageDown => PageDown,
             CrosstermKeyCode::F(num) => F(num),
             CrosstermKeyCode::Null => Null,
+            CrosstermKeyCode::CapsLock => CapsLock,
+            CrosstermKeyCode::ScrollLock => ScrollLock,
+            CrosstermKeyCode::NumLock => NumLock,
+            CrosstermKeyCode::PrintScreen => PrintScreen,
+            CrosstermKeyCode::Pause => Pause,
+            CrosstermKeyCode::Menu => Menu,
+            CrosstermKeyCode::KeypadBegin => Keypad(KeypadKey::Begin),
+            CrosstermKeyCode::Media(media_key) => Media(media_key.into()),
+            CrosstermKeyCode::Modifier(modifier_key) => Modifier(modifier_key.into()),
+        }
+    }
+}
 
-            key_code => return Err(ConversionError::UnknownKey { key_code }),
-            // TODO maybe handle these later if needed
-            // CrosstermKeyCode::CapsLock => todo!(),
-            // CrosstermKeyCode::ScrollLock => todo!(),
-            // CrosstermKeyCode::NumLock => todo!(),
-            // CrosstermKeyCode::PrintScreen => todo!(),
-            // CrosstermKeyCode::Pause => todo!(),
-            // CrosstermKeyCode::Menu => todo!(),
-            // CrosstermKeyCode::KeypadBegin => todo!(),
-            // CrosstermKeyCode::Media(_) => todo!(),
-            // CrosstermKeyCode::Modifier(_) => todo!(),
-        };
-        Ok(key)
+impl From<CrosstermMediaKeyCode> for MediaKey {
+    fn from(media_key: CrosstermMediaKeyCode) -> Self {
+        use CrosstermMediaKeyCode::*;
+        match media_key {
+            Play => MediaKey::Play,
+            Pause => MediaKey::Pause,
+            PlayPause => MediaKey::PlayPause,
+            Reverse => MediaKey::Reverse,
+            Stop => MediaKey::Stop,
+            FastForward => MediaKey::FastForward,
+            Rewind => MediaKey::Rewind,
+            TrackNext => MediaKey::TrackNext,
+            TrackPrevious => MediaKey::TrackPrevious,
+            Record => MediaKey::Record,
+            LowerVolume => MediaKey::LowerVolume,
+            RaiseVolume => MediaKey::RaiseVolume,
+            MuteVolume => MediaKey::MuteVolume,
+        }
+    }
+}
+
+impl From<CrosstermModifierKeyCode> for ModifierKey {
+    fn from(modifier_key: CrosstermModifierKeyCode) -> Self {
+        use CrosstermModifierKeyCode::*;
+        match modifier_key {
+            LeftShift => ModifierKey::LeftShift,
+            LeftControl => ModifierKey::LeftControl,
+            LeftAlt => ModifierKey::LeftAlt,
+            LeftSuper => ModifierKey::LeftSuper,
+            LeftHyper => ModifierKey::LeftHyper,
+            LeftMeta => ModifierKey::LeftMeta,
+            RightShift => ModifierKey::RightShift,
+            RightControl => ModifierKey::RightControl,
+            RightAlt => ModifierKey::RightAlt,
+            RightSuper => ModifierKey::RightSuper,
+            RightHyper => ModifierKey::RightHyper,
+            RightMeta => ModifierKey::RightMeta,
+            IsoLevel3Shift => ModifierKey::IsoLevel3Shift,
+            IsoLevel5Shift => ModifierKey::IsoLevel5Shift,
+        }
     }
 }
 
-impl From<CrosstermKeyModifiers> for KeyModifiers {
-    fn from(modifiers: CrosstermKeyModifiers) -> Self {
-        // our modifiers area superset of crossterm's modifiers and are bit compatible so this is
-        // safe
-        KeyModifiers::from_bits(modifiers.bits()).unwrap()
+/// Our modifiers are a superset of crossterm's named modifiers and are bit compatible, so only
+/// bits that crossterm has no name for fail to convert.
+impl TryFrom<CrosstermKeyModifiers> for KeyModifiers {
+    type Error = ConversionError;
+    fn try_from(modifiers: CrosstermKeyModifiers) -> Result<Self, Self::Error> {
+        KeyModifiers::from_bits(modifiers.bits()).ok_or_else(|| ConversionError::UnknownModifiers {
+            backend: BACKEND,
+            modifiers: format!("{:#010b}", modifiers.bits()),
+        })
     }
 }
 
-impl From<CrosstermMouseEvent> for MouseEvent {
-    fn from(mouse_event: CrosstermMouseEvent) -> Self {
-        MouseEvent {
+impl TryFrom<CrosstermMouseEvent> for MouseEvent {
+    type Error = ConversionError;
+    fn try_from(mouse_event: CrosstermMouseEvent) -> Result<Self, Self::Error> {
+        Ok(MouseEvent {
             column: mouse_event.column,
             row: mouse_event.row,
             kind: mouse_event.kind.into(),
-            modifiers: mouse_event.modifiers.into(),
-        }
+            modifiers: mouse_event.modifiers.try_into()?,
+        })
     }
 }
 use crossterm::event::MouseButton as CrosstermMouseButton;
@@ -166,7 +222,89 @@ mod tests {
         #[case] modifiers: CrosstermKeyModifiers,
         #[case] expected: KeyModifiers,
     ) {
-        let result = KeyModifiers::from(modifiers);
-        assert_eq!(result, expected);
+        let result = KeyModifiers::try_from(modifiers);
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn try_from_unknown_modifiers() {
+        let modifiers = CrosstermKeyModifiers::from_bits_retain(0b1000_0001);
+        let error = KeyModifiers::try_from(modifiers).unwrap_err();
+        assert_eq!(
+            error,
+            ConversionError::UnknownModifiers {
+                backend: "crossterm",
+                modifiers: "0b10000001".into()
+            }
+        );
+        assert!(error.is_unsupported());
+
+        let key_event = CrosstermKeyEvent::new(CrosstermKeyCode::Char('q'), modifiers);
+        assert_eq!(
+            Event::from_crossterm(CrosstermEvent::Key(key_event)),
+            Ok(None)
+        );
+    }
+
+    #[rstest]
+    #[case(KeyEventKind::Press)]
+    #[case(KeyEventKind::Release)]
+    #[case(KeyEventKind::Repeat)]
+    fn try_from_key_event_kind(#[case] kind: KeyEventKind) {
+        let key_event = CrosstermKeyEvent::new_with_kind(
+            CrosstermKeyCode::Char('a'),
+            CrosstermKeyModifiers::CONTROL,
+            kind,
+        );
+        let key = Key::Char('a');
+        let modifiers = KeyModifiers::CTRL;
+        let event = Event::try_from(CrosstermEvent::Key(key_event));
+        match (kind, event) {
+            (KeyEventKind::Press, Ok(Event::KeyPressed(event)))
+            | (KeyEventKind::Repeat, Ok(Event::KeyRepeated(event))) => {
+                assert_eq!(event, KeyPressedEvent { key, modifiers });
+            }
+            (KeyEventKind::Release, Ok(Event::KeyReleased(event))) => {
+                assert_eq!(event, KeyReleasedEvent { key, modifiers });
+            }
+            _ => panic!("unexpected conversion for {kind:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(CrosstermEvent::Paste("hello".into()), Event::Paste("hello".into()))]
+    #[case(CrosstermEvent::Resize(80, 24), Event::Resize { columns: 80, rows: 24 })]
+    #[case(CrosstermEvent::FocusGained, Event::FocusGained)]
+    #[case(CrosstermEvent::FocusLost, Event::FocusLost)]
+    fn try_from_event(#[case] event: CrosstermEvent, #[case] expected: Event) {
+        assert_eq!(Event::try_from(event), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(CrosstermKeyCode::CapsLock, Key::CapsLock)]
+    #[case(CrosstermKeyCode::ScrollLock, Key::ScrollLock)]
+    #[case(CrosstermKeyCode::NumLock, Key::NumLock)]
+    #[case(CrosstermKeyCode::PrintScreen, Key::PrintScreen)]
+    #[case(CrosstermKeyCode::Pause, Key::Pause)]
+    #[case(CrosstermKeyCode::Menu, Key::Menu)]
+    #[case(CrosstermKeyCode::KeypadBegin, Key::Keypad(KeypadKey::Begin))]
+    #[case(
+        CrosstermKeyCode::Media(CrosstermMediaKeyCode::PlayPause),
+        Key::Media(MediaKey::PlayPause)
+    )]
+    #[case(
+        CrosstermKeyCode::Media(CrosstermMediaKeyCode::MuteVolume),
+        Key::Media(MediaKey::MuteVolume)
+    )]
+    #[case(
+        CrosstermKeyCode::Modifier(CrosstermModifierKeyCode::LeftShift),
+        Key::Modifier(ModifierKey::LeftShift)
+    )]
+    #[case(
+        CrosstermKeyCode::Modifier(CrosstermModifierKeyCode::IsoLevel3Shift),
+        Key::Modifier(ModifierKey::IsoLevel3Shift)
+    )]
+    fn from_key_code(#[case] key_code: CrosstermKeyCode, #[case] expected: Key) {
+        assert_eq!(Key::from(key_code), expected);
     }
 }
@@ -0,0 +1,418 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    io,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use crossterm::event::{Event as CrosstermEvent, EventStream as CrosstermEventStream};
+use futures_core::Stream;
+use tokio::{
+    sync::mpsc,
+    time::{self, Instant, Interval, MissedTickBehavior, Sleep},
+};
+
+use super::{Event, TimerId};
+#[cfg(feature = "theme-file")]
+use crate::theme::file::{ThemeFile, Watcher};
+
+/// An asynchronous stream of [`Event`]s read from the terminal, merged with ticks and timers
+///
+/// Ticks are produced at the rate set with [`EventStream::with_tick_rate`]. Timers are registered
+/// through the [`Timers`] handle returned by [`EventStream::timers`], which can be cloned and
+/// handed to the widgets that need them.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use futures::StreamExt;
+/// use ratatui_widgets::events::{Event, EventStream};
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() -> std::io::Result<()> {
+///     let mut events = EventStream::new().with_tick_rate(Duration::from_millis(250));
+///     let blink = events.timers().set_interval(Duration::from_millis(500));
+///     while let Some(event) = events.next().await {
+///         match event? {
+///             Event::Tick => { /* advance animations */ }
+///             Event::Timer(id) if id == blink => { /* toggle the cursor */ }
+///             _ => {}
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct EventStream<S = CrosstermEventStream> {
+    events: S,
+    tick_rate: Option<Duration>,
+    tick: Option<Interval>,
+    timers: Timers,
+    commands: mpsc::UnboundedReceiver<TimerCommand>,
+    scheduled: HashMap<TimerId, ScheduledTimer>,
+    sleep: Option<Pin<Box<Sleep>>>,
+    #[cfg(feature = "theme-file")]
+    theme_watcher: Option<Watcher>,
+    /// A reloaded theme waiting to be produced after the tick that detected it
+    #[cfg(feature = "theme-file")]
+    theme_change: Option<ThemeFile>,
+}
+
+/// A handle for registering timers with an [`EventStream`]
+///
+/// When a timer fires, the stream produces an [`Event::Timer`] with the id returned when the timer
+/// was registered. Timers registered after the stream has been dropped never fire.
+#[derive(Debug, Clone)]
+pub struct Timers {
+    commands: mpsc::UnboundedSender<TimerCommand>,
+    next_id: Arc<AtomicU64>,
+}
+
+#[derive(Debug)]
+enum TimerCommand {
+    Start {
+        id: TimerId,
+        deadline: Instant,
+        period: Option<Duration>,
+    },
+    Cancel(TimerId),
+}
+
+#[derive(Debug)]
+struct ScheduledTimer {
+    deadline: Instant,
+    period: Option<Duration>,
+}
+
+impl EventStream {
+    /// Creates a stream of the events read from the terminal using crossterm.
+    pub fn new() -> Self {
+        Self::from_stream(CrosstermEventStream::new())
+    }
+}
+
+impl Default for EventStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> EventStream<S>
+where
+    S: Stream<Item = io::Result<CrosstermEvent>> + Unpin,
+{
+    /// Creates a stream that reads crossterm events from the given stream instead of the terminal.
+    pub fn from_stream(events: S) -> Self {
+        let (sender, commands) = mpsc::unbounded_channel();
+        Self {
+            events,
+            tick_rate: None,
+            tick: None,
+            timers: Timers {
+                commands: sender,
+                next_id: Arc::new(AtomicU64::new(0)),
+            },
+            commands,
+            scheduled: HashMap::new(),
+            sleep: None,
+            #[cfg(feature = "theme-file")]
+            theme_watcher: None,
+            #[cfg(feature = "theme-file")]
+            theme_change: None,
+        }
+    }
+
+    /// Produces an [`Event::Tick`] at the given rate. Ticks that are missed because the consumer
+    /// was busy are skipped rather than delivered in a burst.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tick rate is zero.
+    pub fn with_tick_rate(mut self, tick_rate: Duration) -> Self {
+        assert!(!tick_rate.is_zero(), "tick rate must be non-zero");
+        self.tick_rate = Some(tick_rate);
+        self.tick = None;
+        self
+    }
+
+    /// Polls the watcher on each tick, producing an [`Event::ThemeChanged`] after the tick when the
+    /// theme file has been reloaded. This needs a tick rate to be set with
+    /// [`EventStream::with_tick_rate`].
+    ///
+    /// A file that fails to load is skipped, so the widgets keep their theme until the file is
+    /// fixed. Poll a [`Watcher`] directly to report the error instead.
+    #[cfg(feature = "theme-file")]
+    pub fn with_theme_watcher(mut self, watcher: Watcher) -> Self {
+        self.theme_watcher = Some(watcher);
+        self
+    }
+
+    /// Returns a handle for registering timers with this stream.
+    pub fn timers(&self) -> Timers {
+        self.timers.clone()
+    }
+
+    fn receive_commands(&mut self, cx: &mut Context<'_>) {
+        while let Poll::Ready(Some(command)) = self.commands.poll_recv(cx) {
+            match command {
+                TimerCommand::Start {
+                    id,
+                    deadline,
+                    period,
+                } => {
+                    self.scheduled
+                        .insert(id, ScheduledTimer { deadline, period });
+                }
+                TimerCommand::Cancel(id) => {
+                    self.scheduled.remove(&id);
+                }
+            }
+        }
+    }
+
+    /// Returns the id of the next timer if it has expired, otherwise registers for a wake up when
+    /// it expires.
+    fn poll_timers(&mut self, cx: &mut Context<'_>) -> Option<TimerId> {
+        let (id, deadline) = self
+            .scheduled
+            .iter()
+            .map(|(&id, timer)| (id, timer.deadline))
+            .min_by_key(|&(id, deadline)| (deadline, id))?;
+        let sleep = self
+            .sleep
+            .get_or_insert_with(|| Box::pin(time::sleep_until(deadline)));
+        if sleep.deadline() != deadline {
+            sleep.as_mut().reset(deadline);
+        }
+        if sleep.as_mut().poll(cx).is_pending() {
+            return None;
+        }
+        let timer = self.scheduled.get_mut(&id)?;
+        match timer.period {
+            Some(period) => {
+                // skip missed intervals rather than firing them in a burst
+                let now = Instant::now();
+                timer.deadline = (deadline + period).max(now);
+            }
+            None => {
+                self.scheduled.remove(&id);
+            }
+        }
+        Some(id)
+    }
+
+    fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        let Some(tick_rate) = self.tick_rate else {
+            return Poll::Pending;
+        };
+        let tick = self.tick.get_or_insert_with(|| {
+            let mut interval = time::interval_at(Instant::now() + tick_rate, tick_rate);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            interval
+        });
+        tick.poll_tick(cx).map(|_| ())
+    }
+}
+
+impl<S> Stream for EventStream<S>
+where
+    S: Stream<Item = io::Result<CrosstermEvent>> + Unpin,
+{
+    type Item = io::Result<Event>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        #[cfg(feature = "theme-file")]
+        if let Some(theme) = this.theme_change.take() {
+            return Poll::Ready(Some(Ok(Event::ThemeChanged(theme))));
+        }
+        this.receive_commands(cx);
+        if let Some(id) = this.poll_timers(cx) {
+            return Poll::Ready(Some(Ok(Event::Timer(id))));
+        }
+        if this.poll_tick(cx).is_ready() {
+            #[cfg(feature = "theme-file")]
+            {
+                this.theme_change = this
+                    .theme_watcher
+                    .as_mut()
+                    .and_then(|watcher| watcher.poll()?.ok());
+            }
+            return Poll::Ready(Some(Ok(Event::Tick)));
+        }
+        loop {
+            match Pin::new(&mut this.events).poll_next(cx) {
+                Poll::Ready(Some(Ok(event))) => match Event::from_crossterm(event) {
+                    Ok(Some(event)) => return Poll::Ready(Some(Ok(event))),
+                    // skip events that have no equivalent
+                    Ok(None) => {}
+                    Err(error) => {
+                        let error = io::Error::new(io::ErrorKind::InvalidData, error);
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                },
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl Timers {
+    /// Registers a timer that fires once after the given delay.
+    pub fn set_timeout(&self, delay: Duration) -> TimerId {
+        self.start(delay, None)
+    }
+
+    /// Registers a timer that fires repeatedly with the given period, starting one period from
+    /// now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the period is zero.
+    pub fn set_interval(&self, period: Duration) -> TimerId {
+        assert!(!period.is_zero(), "timer period must be non-zero");
+        self.start(period, Some(period))
+    }
+
+    /// Cancels a timer. Cancelling a timer that has already fired or been cancelled does nothing.
+    pub fn cancel(&self, id: TimerId) {
+        // the stream has been dropped, so there is nothing to cancel
+        let _ = self.commands.send(TimerCommand::Cancel(id));
+    }
+
+    fn start(&self, delay: Duration, period: Option<Duration>) -> TimerId {
+        let id = TimerId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        let deadline = Instant::now() + delay;
+        // the stream has been dropped, so the timer would never be observed
+        let _ = self.commands.send(TimerCommand::Start {
+            id,
+            deadline,
+            period,
+        });
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    fn pending_stream() -> EventStream<stream::Pending<io::Result<CrosstermEvent>>> {
+        EventStream::from_stream(stream::pending())
+    }
+
+    async fn next_event<S>(events: &mut EventStream<S>) -> Event
+    where
+        S: Stream<Item = io::Result<CrosstermEvent>> + Unpin,
+    {
+        events.next().await.unwrap().unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn converts_terminal_events() {
+        let mut events = EventStream::from_stream(stream::iter([
+            Ok(CrosstermEvent::FocusGained),
+            Ok(CrosstermEvent::Resize(80, 24)),
+            Err(io::Error::other("read failed")),
+        ]));
+        assert_eq!(next_event(&mut events).await, Event::FocusGained);
+        assert_eq!(
+            next_event(&mut events).await,
+            Event::Resize {
+                columns: 80,
+                rows: 24
+            }
+        );
+        assert!(events.next().await.unwrap().is_err());
+        assert!(events.next().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn ticks() {
+        let mut events = pending_stream().with_tick_rate(Duration::from_millis(100));
+        let start = Instant::now();
+        assert_eq!(next_event(&mut events).await, Event::Tick);
+        assert_eq!(next_event(&mut events).await, Event::Tick);
+        assert_eq!(start.elapsed(), Duration::from_millis(200));
+    }
+
+    #[cfg(feature = "theme-file")]
+    #[tokio::test(start_paused = true)]
+    async fn theme_changes_follow_ticks() {
+        use std::{fs, time::SystemTime};
+
+        use crate::theme::file::{Format, ThemeFile};
+
+        let path = std::env::temp_dir().join(format!(
+            "ratatui-widgets-{}-stream-theme.toml",
+            std::process::id()
+        ));
+        let write = |contents: &str, seconds: u64| {
+            fs::write(&path, contents).unwrap();
+            let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+            let file = fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(modified).unwrap();
+        };
+        write("", 1);
+        let mut events = pending_stream()
+            .with_tick_rate(Duration::from_millis(100))
+            .with_theme_watcher(Watcher::new(&path));
+        assert_eq!(next_event(&mut events).await, Event::Tick);
+
+        let contents = "[button]\nshadow = \"red\"\n";
+        write(contents, 2);
+        assert_eq!(next_event(&mut events).await, Event::Tick);
+        let theme = ThemeFile::parse(contents, Format::Toml).unwrap();
+        assert_eq!(next_event(&mut events).await, Event::ThemeChanged(theme));
+        assert_eq!(next_event(&mut events).await, Event::Tick);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timers() {
+        let mut events = pending_stream();
+        let timers = events.timers();
+        let start = Instant::now();
+        let timeout = timers.set_timeout(Duration::from_millis(50));
+        let interval = timers.set_interval(Duration::from_millis(30));
+
+        let mut fired = vec![];
+        for _ in 0..4 {
+            let event = next_event(&mut events).await;
+            fired.push((event, start.elapsed().as_millis()));
+        }
+        assert_eq!(
+            fired,
+            [
+                (Event::Timer(interval), 30),
+                (Event::Timer(timeout), 50),
+                (Event::Timer(interval), 60),
+                (Event::Timer(interval), 90),
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancel_timer() {
+        let mut events = pending_stream();
+        let timers = events.timers();
+        let cancelled = timers.set_timeout(Duration::from_millis(10));
+        let kept = timers.set_timeout(Duration::from_millis(20));
+        timers.cancel(cancelled);
+
+        assert_eq!(next_event(&mut events).await, Event::Timer(kept));
+        let next = time::timeout(Duration::from_secs(1), events.next()).await;
+        assert!(next.is_err(), "no more timers should fire");
+    }
+}
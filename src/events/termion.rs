@@ -1,30 +1,263 @@
-use termion::event::Key as TermionKey;
+use termion::event::{
+    Event as TermionEvent, Key as TermionKey, MouseButton as TermionMouseButton,
+    MouseEvent as TermionMouseEvent,
+};
 
-use super::Key;
+use super::{
+    ConversionError, Event, Key, KeyModifiers, KeyPressedEvent, MouseButton, MouseEvent,
+    MouseEventKind,
+};
 
+const BACKEND: &str = "termion";
+
+impl Event {
+    /// Converts a termion event, returning `Ok(None)` if the event has no equivalent, and an error
+    /// if termion produced an event that could not be interpreted.
+    ///
+    /// Use [`Event::try_from`] to find out why an event was not converted.
+    pub fn from_termion(event: TermionEvent) -> Result<Option<Event>, ConversionError> {
+        super::skip_unsupported(Event::try_from(event))
+    }
+}
+
+impl TryFrom<TermionEvent> for Event {
+    type Error = ConversionError;
+    fn try_from(event: TermionEvent) -> Result<Self, Self::Error> {
+        let event = match event {
+            TermionEvent::Key(key) => Event::KeyPressed(key.try_into()?),
+            TermionEvent::Mouse(mouse_event) => Event::Mouse(mouse_event.into()),
+            TermionEvent::Unsupported(bytes) => {
+                return Err(ConversionError::UnsupportedEvent {
+                    backend: BACKEND,
+                    event: format!("{bytes:?}"),
+                })
+            }
+        };
+        Ok(event)
+    }
+}
+
+/// Termion folds the modifiers into the key itself, so the conversion produces a full
+/// [`KeyPressedEvent`] rather than just a [`Key`].
+impl TryFrom<TermionKey> for KeyPressedEvent {
+    type Error = ConversionError;
+    fn try_from(key: TermionKey) -> Result<Self, Self::Error> {
+        use Key::*;
+        let none = KeyModifiers::empty();
+        let (key, modifiers) = match key {
+            // termion reports enter and tab as plain characters
+            TermionKey::Char('\n') => (Enter, none),
+            TermionKey::Char('\t') => (Tab, none),
+            TermionKey::Char(c) => (Char(c), none),
+            TermionKey::Alt(c) => (Char(c), KeyModifiers::ALT),
+            TermionKey::Ctrl(c) => (Char(c), KeyModifiers::CTRL),
+            TermionKey::Backspace => (Backspace, none),
+            TermionKey::Left => (Left, none),
+            TermionKey::ShiftLeft => (Left, KeyModifiers::SHIFT),
+            TermionKey::AltLeft => (Left, KeyModifiers::ALT),
+            TermionKey::CtrlLeft => (Left, KeyModifiers::CTRL),
+            TermionKey::Right => (Right, none),
+            TermionKey::ShiftRight => (Right, KeyModifiers::SHIFT),
+            TermionKey::AltRight => (Right, KeyModifiers::ALT),
+            TermionKey::CtrlRight => (Right, KeyModifiers::CTRL),
+            TermionKey::Up => (Up, none),
+            TermionKey::ShiftUp => (Up, KeyModifiers::SHIFT),
+            TermionKey::AltUp => (Up, KeyModifiers::ALT),
+            TermionKey::CtrlUp => (Up, KeyModifiers::CTRL),
+            TermionKey::Down => (Down, none),
+            TermionKey::ShiftDown => (Down, KeyModifiers::SHIFT),
+            TermionKey::AltDown => (Down, KeyModifiers::ALT),
+            TermionKey::CtrlDown => (Down, KeyModifiers::CTRL),
+            TermionKey::Home => (Home, none),
+            TermionKey::CtrlHome => (Home, KeyModifiers::CTRL),
+            TermionKey::End => (End, none),
+            TermionKey::CtrlEnd => (End, KeyModifiers::CTRL),
+            TermionKey::PageUp => (PageUp, none),
+            TermionKey::PageDown => (PageDown, none),
+            TermionKey::BackTab => (BackTab, KeyModifiers::SHIFT),
+            TermionKey::Delete => (Delete, none),
+            TermionKey::Insert => (Insert, none),
+            TermionKey::F(n) => (F(n), none),
+            TermionKey::Null => (Null, none),
+            TermionKey::Esc => (Esc, none),
+            // termion uses this internally to mark partially parsed sequences
+            key => {
+                return Err(ConversionError::InvalidEvent {
+                    backend: BACKEND,
+                    event: format!("{key:?}"),
+                })
+            }
+        };
+        Ok(KeyPressedEvent { key, modifiers })
+    }
+}
+
+/// Converts just the key, dropping any modifiers termion folded into it. Keys that have no
+/// equivalent become [`Key::Null`]. Convert to a [`KeyPressedEvent`] to keep the modifiers.
 impl From<TermionKey> for Key {
     fn from(key: TermionKey) -> Self {
-        use Key::*;
-        match key {
-            TermionKey::Backspace => Backspace,
-            TermionKey::Left => Left,
-            TermionKey::Right => Right,
-            TermionKey::Up => Up,
-            TermionKey::Down => Down,
-            TermionKey::Home => Home,
-            TermionKey::End => End,
-            TermionKey::PageUp => PageUp,
-            TermionKey::PageDown => PageDown,
-            TermionKey::BackTab => BackTab,
-            TermionKey::Delete => Delete,
-            TermionKey::Insert => Insert,
-            TermionKey::F(n) => F(n),
-            TermionKey::Char(c) => Char(c),
-            TermionKey::Alt(c) => Char(c),
-            TermionKey::Ctrl(c) => Char(c),
-            TermionKey::Null => Null,
-            TermionKey::Esc => Esc,
-            _ => Null,
+        KeyPressedEvent::try_from(key).map_or(Key::Null, |event| event.key)
+    }
+}
+
+/// Termion does not report which button was released or dragged, nor any modifiers, so releases
+/// and holds are reported as the left button. Termion coordinates are one-based and are converted
+/// to the zero-based coordinates used by Ratatui.
+impl From<TermionMouseEvent> for MouseEvent {
+    fn from(mouse_event: TermionMouseEvent) -> Self {
+        let (kind, column, row) = match mouse_event {
+            TermionMouseEvent::Press(button, column, row) => (button.into(), column, row),
+            TermionMouseEvent::Release(column, row) => {
+                (MouseEventKind::Up(MouseButton::Left), column, row)
+            }
+            TermionMouseEvent::Hold(column, row) => {
+                (MouseEventKind::Drag(MouseButton::Left), column, row)
+            }
+        };
+        MouseEvent {
+            column: column.saturating_sub(1),
+            row: row.saturating_sub(1),
+            kind,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+}
+
+impl From<TermionMouseButton> for MouseEventKind {
+    fn from(mouse_button: TermionMouseButton) -> Self {
+        match mouse_button {
+            TermionMouseButton::Left => MouseEventKind::Down(MouseButton::Left),
+            TermionMouseButton::Right => MouseEventKind::Down(MouseButton::Right),
+            TermionMouseButton::Middle => MouseEventKind::Down(MouseButton::Middle),
+            TermionMouseButton::WheelUp => MouseEventKind::ScrollUp,
+            TermionMouseButton::WheelDown => MouseEventKind::ScrollDown,
+            TermionMouseButton::WheelLeft => MouseEventKind::ScrollLeft,
+            TermionMouseButton::WheelRight => MouseEventKind::ScrollRight,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(TermionKey::Char('a'), Key::Char('a'), KeyModifiers::empty())]
+    #[case(TermionKey::Char('\n'), Key::Enter, KeyModifiers::empty())]
+    #[case(TermionKey::Char('\t'), Key::Tab, KeyModifiers::empty())]
+    #[case(TermionKey::Alt('x'), Key::Char('x'), KeyModifiers::ALT)]
+    #[case(TermionKey::Ctrl('c'), Key::Char('c'), KeyModifiers::CTRL)]
+    #[case(TermionKey::ShiftLeft, Key::Left, KeyModifiers::SHIFT)]
+    #[case(TermionKey::AltRight, Key::Right, KeyModifiers::ALT)]
+    #[case(TermionKey::CtrlUp, Key::Up, KeyModifiers::CTRL)]
+    #[case(TermionKey::CtrlEnd, Key::End, KeyModifiers::CTRL)]
+    #[case(TermionKey::BackTab, Key::BackTab, KeyModifiers::SHIFT)]
+    #[case(TermionKey::F(5), Key::F(5), KeyModifiers::empty())]
+    #[case(TermionKey::Esc, Key::Esc, KeyModifiers::empty())]
+    fn try_from_key(
+        #[case] key: TermionKey,
+        #[case] expected_key: Key,
+        #[case] expected_modifiers: KeyModifiers,
+    ) {
+        let expected = KeyPressedEvent {
+            key: expected_key,
+            modifiers: expected_modifiers,
+        };
+        assert_eq!(KeyPressedEvent::try_from(key), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(TermionKey::Char('a'), Key::Char('a'))]
+    #[case(TermionKey::Char('\n'), Key::Enter)]
+    #[case(TermionKey::Alt('x'), Key::Char('x'))]
+    #[case(TermionKey::Ctrl('c'), Key::Char('c'))]
+    #[case(TermionKey::ShiftLeft, Key::Left)]
+    #[case(TermionKey::__IsNotComplete, Key::Null)]
+    fn from_key(#[case] key: TermionKey, #[case] expected: Key) {
+        assert_eq!(Key::from(key), expected);
+    }
+
+    #[test]
+    fn try_from_incomplete_key() {
+        let error = KeyPressedEvent::try_from(TermionKey::__IsNotComplete).unwrap_err();
+        assert_eq!(
+            error,
+            ConversionError::InvalidEvent {
+                backend: "termion",
+                event: "__IsNotComplete".into()
+            }
+        );
+        assert!(!error.is_unsupported());
+
+        let event = TermionEvent::Key(TermionKey::__IsNotComplete);
+        assert_eq!(Event::from_termion(event), Err(error));
+    }
+
+    #[rstest]
+    #[case(
+        TermionMouseEvent::Press(TermionMouseButton::Left, 1, 1),
+        MouseEventKind::Down(MouseButton::Left),
+        0,
+        0
+    )]
+    #[case(
+        TermionMouseEvent::Press(TermionMouseButton::Right, 5, 10),
+        MouseEventKind::Down(MouseButton::Right),
+        4,
+        9
+    )]
+    #[case(
+        TermionMouseEvent::Press(TermionMouseButton::WheelUp, 2, 3),
+        MouseEventKind::ScrollUp,
+        1,
+        2
+    )]
+    #[case(
+        TermionMouseEvent::Press(TermionMouseButton::WheelDown, 2, 3),
+        MouseEventKind::ScrollDown,
+        1,
+        2
+    )]
+    #[case(
+        TermionMouseEvent::Release(3, 4),
+        MouseEventKind::Up(MouseButton::Left),
+        2,
+        3
+    )]
+    #[case(
+        TermionMouseEvent::Hold(3, 4),
+        MouseEventKind::Drag(MouseButton::Left),
+        2,
+        3
+    )]
+    fn from_mouse_event(
+        #[case] mouse_event: TermionMouseEvent,
+        #[case] kind: MouseEventKind,
+        #[case] column: u16,
+        #[case] row: u16,
+    ) {
+        let expected = MouseEvent {
+            column,
+            row,
+            kind,
+            modifiers: KeyModifiers::empty(),
+        };
+        assert_eq!(MouseEvent::from(mouse_event), expected);
+    }
+
+    #[test]
+    fn try_from_unsupported_event() {
+        let event = TermionEvent::Unsupported(vec![0x1b, 0x5b]);
+        let error = Event::try_from(event.clone()).unwrap_err();
+        assert_eq!(
+            error,
+            ConversionError::UnsupportedEvent {
+                backend: "termion",
+                event: "[27, 91]".into()
+            }
+        );
+        assert!(error.is_unsupported());
+        assert_eq!(Event::from_termion(event), Ok(None));
+    }
+}
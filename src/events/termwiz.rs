@@ -1,100 +1,470 @@
-use termwiz::input::KeyCode as TermwizKeyCode;
+use termwiz::input::{
+    InputEvent, KeyCode as TermwizKeyCode, KeyEvent as TermwizKeyEvent,
+    Modifiers as TermwizModifiers, MouseButtons as TermwizMouseButtons,
+    MouseEvent as TermwizMouseEvent,
+};
 
-use super::Key;
+use super::{
+    BrowserKey, ConversionError, Event, Key, KeyModifiers, KeyPressedEvent, KeypadKey, MediaKey,
+    ModifierKey, MouseButton, MouseEvent, MouseEventKind,
+};
 
-impl From<TermwizKeyCode> for Key {
-    fn from(key_code: TermwizKeyCode) -> Self {
+const BACKEND: &str = "termwiz";
+
+/// Termwiz mouse coordinates are one-based on unix (SGR reports) and zero-based on windows.
+const MOUSE_ORIGIN: u16 = if cfg!(windows) { 0 } else { 1 };
+
+impl Event {
+    /// Converts a termwiz input event, returning `Ok(None)` if the event has no equivalent.
+    ///
+    /// Use [`Event::try_from`] to find out why an event was not converted.
+    pub fn from_termwiz(event: InputEvent) -> Result<Option<Event>, ConversionError> {
+        super::skip_unsupported(Event::try_from(event))
+    }
+}
+
+impl TryFrom<InputEvent> for Event {
+    type Error = ConversionError;
+    fn try_from(event: InputEvent) -> Result<Self, Self::Error> {
+        let event = match event {
+            InputEvent::Key(key_event) => Event::KeyPressed(key_event.try_into()?),
+            InputEvent::Mouse(mouse_event) => Event::Mouse(mouse_event.into()),
+            InputEvent::Resized { cols, rows } => Event::Resize {
+                columns: u16::try_from(cols).unwrap_or(u16::MAX),
+                rows: u16::try_from(rows).unwrap_or(u16::MAX),
+            },
+            InputEvent::Paste(text) => Event::Paste(text),
+            _ => {
+                return Err(ConversionError::UnsupportedEvent {
+                    backend: BACKEND,
+                    event: format!("{event:?}"),
+                })
+            }
+        };
+        Ok(event)
+    }
+}
+
+impl TryFrom<TermwizKeyEvent> for KeyPressedEvent {
+    type Error = ConversionError;
+    fn try_from(key_event: TermwizKeyEvent) -> Result<Self, Self::Error> {
+        let modifiers = KeyModifiers::from(key_event.modifiers);
+        // termwiz reports shift+tab as a tab with the shift modifier, while crossterm and termion
+        // report a back tab
+        let key = match key_event.key {
+            TermwizKeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => Key::BackTab,
+            key_code => key_code.try_into()?,
+        };
+        Ok(KeyPressedEvent { key, modifiers })
+    }
+}
+
+impl TryFrom<TermwizKeyCode> for Key {
+    type Error = ConversionError;
+    fn try_from(key_code: TermwizKeyCode) -> Result<Self, Self::Error> {
         use Key::*;
-        match key_code {
+        let key = match key_code {
             TermwizKeyCode::Char(c) => Char(c),
-            // TermwizKeyCode::Hyper => Hyper,
-            // TermwizKeyCode::Super => Super,
-            // TermwizKeyCode::Meta => Meta,
-            // TermwizKeyCode::Cancel => Cancel,
             TermwizKeyCode::Backspace => Backspace,
             TermwizKeyCode::Tab => Tab,
-            // TermwizKeyCode::Clear => Clear,
             TermwizKeyCode::Enter => Enter,
-            // TermwizKeyCode::Shift => Shift,
             TermwizKeyCode::Escape => Esc,
-            // TermwizKeyCode::LeftShift => LeftShift,
-            // TermwizKeyCode::RightShift => RightShift,
-            // TermwizKeyCode::Control => Control,
-            // TermwizKeyCode::LeftControl => LeftControl,
-            // TermwizKeyCode::RightControl => RightControl,
-            // TermwizKeyCode::Alt => Alt,
-            // TermwizKeyCode::LeftAlt => LeftAlt,
-            // TermwizKeyCode::RightAlt => RightAlt,
-            // TermwizKeyCode::Menu => Menu,
-            // TermwizKeyCode::LeftMenu => LeftMenu,
-            // TermwizKeyCode::RightMenu => RightMenu,
-            // TermwizKeyCode::Pause => Pause,
-            // TermwizKeyCode::CapsLock => CapsLock,
             TermwizKeyCode::PageUp => PageUp,
             TermwizKeyCode::PageDown => PageDown,
             TermwizKeyCode::End => End,
             TermwizKeyCode::Home => Home,
-            // TermwizKeyCode::LeftArrow => LeftArrow,
-            // TermwizKeyCode::RightArrow => RightArrow,
-            // TermwizKeyCode::UpArrow => UpArrow,
-            // TermwizKeyCode::DownArrow => DownArrow,
-            // TermwizKeyCode::Select => Select,
-            // TermwizKeyCode::Print => Print,
-            // TermwizKeyCode::Execute => Execute,
-            // TermwizKeyCode::PrintScreen => PrintScreen,
+            TermwizKeyCode::LeftArrow | TermwizKeyCode::ApplicationLeftArrow => Left,
+            TermwizKeyCode::RightArrow | TermwizKeyCode::ApplicationRightArrow => Right,
+            TermwizKeyCode::UpArrow | TermwizKeyCode::ApplicationUpArrow => Up,
+            TermwizKeyCode::DownArrow | TermwizKeyCode::ApplicationDownArrow => Down,
             TermwizKeyCode::Insert => Insert,
             TermwizKeyCode::Delete => Delete,
-            // TermwizKeyCode::Help => Help,
-            // TermwizKeyCode::LeftWindows => LeftWindows,
-            // TermwizKeyCode::RightWindows => RightWindows,
-            // TermwizKeyCode::Applications => Applications,
-            // TermwizKeyCode::Sleep => Sleep,
-            // TermwizKeyCode::Numpad0 => Numpad0,
-            // TermwizKeyCode::Numpad1 => Numpad1,
-            // TermwizKeyCode::Numpad2 => Numpad2,
-            // TermwizKeyCode::Numpad3 => Numpad3,
-            // TermwizKeyCode::Numpad4 => Numpad4,
-            // TermwizKeyCode::Numpad5 => Numpad5,
-            // TermwizKeyCode::Numpad6 => Numpad6,
-            // TermwizKeyCode::Numpad7 => Numpad7,
-            // TermwizKeyCode::Numpad8 => Numpad8,
-            // TermwizKeyCode::Numpad9 => Numpad9,
-            // TermwizKeyCode::Multiply => Multiply,
-            // TermwizKeyCode::Add => Add,
-            // TermwizKeyCode::Separator => Separator,
-            // TermwizKeyCode::Subtract => Subtract,
-            // TermwizKeyCode::Decimal => Decimal,
-            // TermwizKeyCode::Divide => Divide,
-            // TermwizKeyCode::Function(n) => Function(n),
-            // TermwizKeyCode::NumLock => NumLock,
-            // TermwizKeyCode::ScrollLock => ScrollLock,
-            // TermwizKeyCode::Copy => Copy,
-            // TermwizKeyCode::Cut => Cut,
-            // TermwizKeyCode::Paste => Paste,
-            // TermwizKeyCode::BrowserBack => BrowserBack,
-            // TermwizKeyCode::BrowserForward => BrowserForward,
-            // TermwizKeyCode::BrowserRefresh => BrowserRefresh,
-            // TermwizKeyCode::BrowserStop => BrowserStop,
-            // TermwizKeyCode::BrowserSearch => BrowserSearch,
-            // TermwizKeyCode::BrowserFavorites => BrowserFavorites,
-            // TermwizKeyCode::BrowserHome => BrowserHome,
-            // TermwizKeyCode::VolumeMute => VolumeMute,
-            // TermwizKeyCode::VolumeDown => VolumeDown,
-            // TermwizKeyCode::VolumeUp => VolumeUp,
-            // TermwizKeyCode::MediaNextTrack => MediaNextTrack,
-            // TermwizKeyCode::MediaPrevTrack => MediaPrevTrack,
-            // TermwizKeyCode::MediaStop => MediaStop,
-            // TermwizKeyCode::MediaPlayPause => MediaPlayPause,
-            // TermwizKeyCode::ApplicationLeftArrow => ApplicationLeftArrow,
-            // TermwizKeyCode::ApplicationRightArrow => ApplicationRightArrow,
-            // TermwizKeyCode::ApplicationUpArrow => ApplicationUpArrow,
-            // TermwizKeyCode::ApplicationDownArrow => ApplicationDownArrow,
-            // TermwizKeyCode::KeyPadHome => KeyPadHome,
-            // TermwizKeyCode::KeyPadEnd => KeyPadEnd,
-            // TermwizKeyCode::KeyPadPageUp => KeyPadPageUp,
-            // TermwizKeyCode::KeyPadPageDown => KeyPadPageDown,
-            // TermwizKeyCode::KeyPadBegin => KeyPadBegin,
-            _ => todo!(),
+            TermwizKeyCode::Function(n) => F(n),
+
+            TermwizKeyCode::CapsLock => CapsLock,
+            TermwizKeyCode::ScrollLock => ScrollLock,
+            TermwizKeyCode::NumLock => NumLock,
+            TermwizKeyCode::PrintScreen => PrintScreen,
+            TermwizKeyCode::Pause => Pause,
+            // termwiz uses the windows virtual key names, where "applications" is the context menu
+            // key and "menu" is the alt key
+            TermwizKeyCode::Applications => Menu,
+
+            TermwizKeyCode::Numpad0 => Keypad(KeypadKey::Digit0),
+            TermwizKeyCode::Numpad1 => Keypad(KeypadKey::Digit1),
+            TermwizKeyCode::Numpad2 => Keypad(KeypadKey::Digit2),
+            TermwizKeyCode::Numpad3 => Keypad(KeypadKey::Digit3),
+            TermwizKeyCode::Numpad4 => Keypad(KeypadKey::Digit4),
+            TermwizKeyCode::Numpad5 => Keypad(KeypadKey::Digit5),
+            TermwizKeyCode::Numpad6 => Keypad(KeypadKey::Digit6),
+            TermwizKeyCode::Numpad7 => Keypad(KeypadKey::Digit7),
+            TermwizKeyCode::Numpad8 => Keypad(KeypadKey::Digit8),
+            TermwizKeyCode::Numpad9 => Keypad(KeypadKey::Digit9),
+            TermwizKeyCode::Multiply => Keypad(KeypadKey::Multiply),
+            TermwizKeyCode::Add => Keypad(KeypadKey::Add),
+            TermwizKeyCode::Separator => Keypad(KeypadKey::Separator),
+            TermwizKeyCode::Subtract => Keypad(KeypadKey::Subtract),
+            TermwizKeyCode::Decimal => Keypad(KeypadKey::Decimal),
+            TermwizKeyCode::Divide => Keypad(KeypadKey::Divide),
+            TermwizKeyCode::KeyPadHome => Keypad(KeypadKey::Home),
+            TermwizKeyCode::KeyPadEnd => Keypad(KeypadKey::End),
+            TermwizKeyCode::KeyPadPageUp => Keypad(KeypadKey::PageUp),
+            TermwizKeyCode::KeyPadPageDown => Keypad(KeypadKey::PageDown),
+            TermwizKeyCode::KeyPadBegin => Keypad(KeypadKey::Begin),
+
+            TermwizKeyCode::MediaPlayPause => Media(MediaKey::PlayPause),
+            TermwizKeyCode::MediaStop => Media(MediaKey::Stop),
+            TermwizKeyCode::MediaNextTrack => Media(MediaKey::TrackNext),
+            TermwizKeyCode::MediaPrevTrack => Media(MediaKey::TrackPrevious),
+            TermwizKeyCode::VolumeDown => Media(MediaKey::LowerVolume),
+            TermwizKeyCode::VolumeUp => Media(MediaKey::RaiseVolume),
+            TermwizKeyCode::VolumeMute => Media(MediaKey::MuteVolume),
+
+            TermwizKeyCode::Shift => Modifier(ModifierKey::Shift),
+            TermwizKeyCode::Control => Modifier(ModifierKey::Control),
+            TermwizKeyCode::Alt | TermwizKeyCode::Menu => Modifier(ModifierKey::Alt),
+            TermwizKeyCode::Super => Modifier(ModifierKey::Super),
+            TermwizKeyCode::Hyper => Modifier(ModifierKey::Hyper),
+            TermwizKeyCode::Meta => Modifier(ModifierKey::Meta),
+            TermwizKeyCode::LeftShift => Modifier(ModifierKey::LeftShift),
+            TermwizKeyCode::LeftControl => Modifier(ModifierKey::LeftControl),
+            TermwizKeyCode::LeftAlt | TermwizKeyCode::LeftMenu => Modifier(ModifierKey::LeftAlt),
+            TermwizKeyCode::LeftWindows => Modifier(ModifierKey::LeftSuper),
+            TermwizKeyCode::RightShift => Modifier(ModifierKey::RightShift),
+            TermwizKeyCode::RightControl => Modifier(ModifierKey::RightControl),
+            TermwizKeyCode::RightAlt | TermwizKeyCode::RightMenu => Modifier(ModifierKey::RightAlt),
+            TermwizKeyCode::RightWindows => Modifier(ModifierKey::RightSuper),
+
+            TermwizKeyCode::BrowserBack => Browser(BrowserKey::Back),
+            TermwizKeyCode::BrowserForward => Browser(BrowserKey::Forward),
+            TermwizKeyCode::BrowserRefresh => Browser(BrowserKey::Refresh),
+            TermwizKeyCode::BrowserStop => Browser(BrowserKey::Stop),
+            TermwizKeyCode::BrowserSearch => Browser(BrowserKey::Search),
+            TermwizKeyCode::BrowserFavorites => Browser(BrowserKey::Favorites),
+            TermwizKeyCode::BrowserHome => Browser(BrowserKey::Home),
+
+            key_code => {
+                return Err(ConversionError::UnsupportedKey {
+                    backend: BACKEND,
+                    key: format!("{key_code:?}"),
+                })
+            }
+        };
+        Ok(key)
+    }
+}
+
+/// The remaining termwiz modifier bits are ignored: `LEADER` is a virtual modifier that only
+/// wezterm's own key bindings set, and `ENHANCED_KEY` marks keys from the extended part of the
+/// keyboard rather than a held modifier.
+impl From<TermwizModifiers> for KeyModifiers {
+    fn from(modifiers: TermwizModifiers) -> Self {
+        // termwiz distinguishes between the left and right variants of some modifiers, which we
+        // don't, so these are folded into the generic modifier
+        let mapping = [
+            (
+                TermwizModifiers::SHIFT
+                    | TermwizModifiers::LEFT_SHIFT
+                    | TermwizModifiers::RIGHT_SHIFT,
+                KeyModifiers::SHIFT,
+            ),
+            (
+                TermwizModifiers::CTRL | TermwizModifiers::LEFT_CTRL | TermwizModifiers::RIGHT_CTRL,
+                KeyModifiers::CTRL,
+            ),
+            (
+                TermwizModifiers::ALT | TermwizModifiers::LEFT_ALT | TermwizModifiers::RIGHT_ALT,
+                KeyModifiers::ALT,
+            ),
+            (TermwizModifiers::SUPER, KeyModifiers::SUPER),
+        ];
+        mapping
+            .into_iter()
+            .filter(|(termwiz, _)| modifiers.intersects(*termwiz))
+            .fold(KeyModifiers::empty(), |acc, (_, ours)| acc | ours)
+    }
+}
+
+/// Converts a mouse event as if no buttons were held before it, so held buttons are converted to
+/// [`MouseEventKind::Down`] and no buttons to [`MouseEventKind::Moved`]. Use a
+/// [`TermwizConverter`] to also get releases and drags.
+impl From<TermwizMouseEvent> for MouseEvent {
+    fn from(mouse_event: TermwizMouseEvent) -> Self {
+        TermwizConverter::new().convert_mouse(mouse_event)
+    }
+}
+
+/// The buttons that are tracked by a [`TermwizConverter`], in the order they are reported when
+/// several change at once
+const BUTTONS: [(TermwizMouseButtons, MouseButton); 3] = [
+    (TermwizMouseButtons::LEFT, MouseButton::Left),
+    (TermwizMouseButtons::RIGHT, MouseButton::Right),
+    (TermwizMouseButtons::MIDDLE, MouseButton::Middle),
+];
+
+/// Converts termwiz input events, keeping track of the held mouse buttons
+///
+/// Termwiz reports which mouse buttons are held rather than when a button is pressed or released.
+/// The converter compares each mouse event with the buttons held before it, to report presses as
+/// [`MouseEventKind::Down`], releases as [`MouseEventKind::Up`] and movement with a button held
+/// as [`MouseEventKind::Drag`]. Use a single converter for all the events of a terminal.
+#[derive(Debug, Default, Clone)]
+pub struct TermwizConverter {
+    held: TermwizMouseButtons,
+}
+
+impl TermwizConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts a termwiz input event like [`Event::from_termwiz`], using the held buttons to
+    /// convert mouse events.
+    pub fn convert(&mut self, event: InputEvent) -> Result<Option<Event>, ConversionError> {
+        match event {
+            InputEvent::Mouse(mouse_event) => {
+                Ok(Some(Event::Mouse(self.convert_mouse(mouse_event))))
+            }
+            event => Event::from_termwiz(event),
         }
     }
+
+    /// Converts a termwiz mouse event and remembers which buttons it holds.
+    pub fn convert_mouse(&mut self, mouse_event: TermwizMouseEvent) -> MouseEvent {
+        MouseEvent {
+            column: mouse_event.x.saturating_sub(MOUSE_ORIGIN),
+            row: mouse_event.y.saturating_sub(MOUSE_ORIGIN),
+            kind: self.convert_buttons(mouse_event.mouse_buttons),
+            modifiers: mouse_event.modifiers.into(),
+        }
+    }
+
+    fn convert_buttons(&mut self, buttons: TermwizMouseButtons) -> MouseEventKind {
+        let positive = buttons.contains(TermwizMouseButtons::WHEEL_POSITIVE);
+        // wheel events don't report the held buttons, so they leave them unchanged
+        if buttons.contains(TermwizMouseButtons::VERT_WHEEL) {
+            return if positive {
+                MouseEventKind::ScrollUp
+            } else {
+                MouseEventKind::ScrollDown
+            };
+        }
+        if buttons.contains(TermwizMouseButtons::HORZ_WHEEL) {
+            return if positive {
+                MouseEventKind::ScrollLeft
+            } else {
+                MouseEventKind::ScrollRight
+            };
+        }
+        let previous = std::mem::replace(&mut self.held, buttons.clone());
+        let find = |flags: &TermwizMouseButtons| {
+            BUTTONS
+                .into_iter()
+                .find(|(flag, _)| flags.contains(flag.clone()))
+                .map(|(_, button)| button)
+        };
+        // a release is reported before a press, so that a button is never left held down
+        if let Some(button) = find(&previous.clone().difference(buttons.clone())) {
+            MouseEventKind::Up(button)
+        } else if let Some(button) = find(&buttons.clone().difference(previous)) {
+            MouseEventKind::Down(button)
+        } else if let Some(button) = find(&buttons) {
+            MouseEventKind::Drag(button)
+        } else {
+            MouseEventKind::Moved
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(TermwizModifiers::NONE, KeyModifiers::empty())]
+    #[case(TermwizModifiers::SHIFT, KeyModifiers::SHIFT)]
+    #[case(TermwizModifiers::LEFT_SHIFT, KeyModifiers::SHIFT)]
+    #[case(TermwizModifiers::RIGHT_SHIFT, KeyModifiers::SHIFT)]
+    #[case(TermwizModifiers::CTRL, KeyModifiers::CTRL)]
+    #[case(TermwizModifiers::RIGHT_CTRL, KeyModifiers::CTRL)]
+    #[case(TermwizModifiers::ALT, KeyModifiers::ALT)]
+    #[case(TermwizModifiers::LEFT_ALT, KeyModifiers::ALT)]
+    #[case(TermwizModifiers::SUPER, KeyModifiers::SUPER)]
+    #[case(
+        TermwizModifiers::SHIFT | TermwizModifiers::CTRL | TermwizModifiers::ALT,
+        KeyModifiers::SHIFT | KeyModifiers::CTRL | KeyModifiers::ALT
+    )]
+    #[case(TermwizModifiers::LEADER, KeyModifiers::empty())]
+    fn from_modifiers(#[case] modifiers: TermwizModifiers, #[case] expected: KeyModifiers) {
+        assert_eq!(KeyModifiers::from(modifiers), expected);
+    }
+
+    #[rstest]
+    #[case(TermwizKeyCode::Char('a'), Key::Char('a'))]
+    #[case(TermwizKeyCode::Escape, Key::Esc)]
+    #[case(TermwizKeyCode::LeftArrow, Key::Left)]
+    #[case(TermwizKeyCode::RightArrow, Key::Right)]
+    #[case(TermwizKeyCode::UpArrow, Key::Up)]
+    #[case(TermwizKeyCode::DownArrow, Key::Down)]
+    #[case(TermwizKeyCode::ApplicationUpArrow, Key::Up)]
+    #[case(TermwizKeyCode::Function(12), Key::F(12))]
+    #[case(TermwizKeyCode::CapsLock, Key::CapsLock)]
+    #[case(TermwizKeyCode::Applications, Key::Menu)]
+    #[case(TermwizKeyCode::Numpad7, Key::Keypad(KeypadKey::Digit7))]
+    #[case(TermwizKeyCode::Divide, Key::Keypad(KeypadKey::Divide))]
+    #[case(TermwizKeyCode::KeyPadBegin, Key::Keypad(KeypadKey::Begin))]
+    #[case(TermwizKeyCode::VolumeUp, Key::Media(MediaKey::RaiseVolume))]
+    #[case(TermwizKeyCode::MediaPlayPause, Key::Media(MediaKey::PlayPause))]
+    #[case(TermwizKeyCode::Menu, Key::Modifier(ModifierKey::Alt))]
+    #[case(TermwizKeyCode::RightControl, Key::Modifier(ModifierKey::RightControl))]
+    #[case(TermwizKeyCode::LeftWindows, Key::Modifier(ModifierKey::LeftSuper))]
+    #[case(TermwizKeyCode::BrowserBack, Key::Browser(BrowserKey::Back))]
+    fn try_from_key_code(#[case] key_code: TermwizKeyCode, #[case] expected: Key) {
+        assert_eq!(Key::try_from(key_code), Ok(expected));
+    }
+
+    #[test]
+    fn try_from_unsupported_key_code() {
+        assert_eq!(
+            Key::try_from(TermwizKeyCode::InternalPasteStart),
+            Err(ConversionError::UnsupportedKey {
+                backend: "termwiz",
+                key: "InternalPasteStart".into()
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_shift_tab() {
+        let key_event = TermwizKeyEvent {
+            key: TermwizKeyCode::Tab,
+            modifiers: TermwizModifiers::SHIFT,
+        };
+        let expected = KeyPressedEvent {
+            key: Key::BackTab,
+            modifiers: KeyModifiers::SHIFT,
+        };
+        assert_eq!(KeyPressedEvent::try_from(key_event), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(TermwizMouseButtons::LEFT, MouseEventKind::Down(MouseButton::Left))]
+    #[case(TermwizMouseButtons::RIGHT, MouseEventKind::Down(MouseButton::Right))]
+    #[case(TermwizMouseButtons::MIDDLE, MouseEventKind::Down(MouseButton::Middle))]
+    #[case(TermwizMouseButtons::NONE, MouseEventKind::Moved)]
+    #[case(
+        TermwizMouseButtons::VERT_WHEEL | TermwizMouseButtons::WHEEL_POSITIVE,
+        MouseEventKind::ScrollUp
+    )]
+    #[case(TermwizMouseButtons::VERT_WHEEL, MouseEventKind::ScrollDown)]
+    #[case(
+        TermwizMouseButtons::HORZ_WHEEL | TermwizMouseButtons::WHEEL_POSITIVE,
+        MouseEventKind::ScrollLeft
+    )]
+    #[case(TermwizMouseButtons::HORZ_WHEEL, MouseEventKind::ScrollRight)]
+    fn from_mouse_buttons(#[case] buttons: TermwizMouseButtons, #[case] expected: MouseEventKind) {
+        assert_eq!(TermwizConverter::new().convert_buttons(buttons), expected);
+    }
+
+    #[test]
+    fn convert_press_drag_release() {
+        let mut converter = TermwizConverter::new();
+        let mut convert = |x, mouse_buttons| {
+            let mouse_event = TermwizMouseEvent {
+                x,
+                y: 1,
+                mouse_buttons,
+                modifiers: TermwizModifiers::NONE,
+            };
+            converter
+                .convert(InputEvent::Mouse(mouse_event))
+                .unwrap()
+                .unwrap()
+        };
+        let mouse = |column, kind| {
+            Event::Mouse(MouseEvent {
+                column,
+                row: 1 - MOUSE_ORIGIN,
+                kind,
+                modifiers: KeyModifiers::empty(),
+            })
+        };
+        const LEFT: TermwizMouseButtons = TermwizMouseButtons::LEFT;
+        const NONE: TermwizMouseButtons = TermwizMouseButtons::NONE;
+        let origin = MOUSE_ORIGIN;
+        assert_eq!(convert(3, NONE), mouse(3 - origin, MouseEventKind::Moved));
+        assert_eq!(
+            convert(3, LEFT),
+            mouse(3 - origin, MouseEventKind::Down(MouseButton::Left))
+        );
+        assert_eq!(
+            convert(4, LEFT),
+            mouse(4 - origin, MouseEventKind::Drag(MouseButton::Left))
+        );
+        assert_eq!(
+            convert(4, NONE),
+            mouse(4 - origin, MouseEventKind::Up(MouseButton::Left))
+        );
+        assert_eq!(convert(5, NONE), mouse(5 - origin, MouseEventKind::Moved));
+    }
+
+    #[test]
+    fn convert_wheel_keeps_held_buttons() {
+        let mut converter = TermwizConverter::new();
+        converter.convert_buttons(TermwizMouseButtons::RIGHT);
+        assert_eq!(
+            converter.convert_buttons(TermwizMouseButtons::VERT_WHEEL),
+            MouseEventKind::ScrollDown
+        );
+        assert_eq!(
+            converter.convert_buttons(TermwizMouseButtons::NONE),
+            MouseEventKind::Up(MouseButton::Right)
+        );
+    }
+
+    #[test]
+    fn from_mouse_event() {
+        let mouse_event = TermwizMouseEvent {
+            x: 5,
+            y: 10,
+            mouse_buttons: TermwizMouseButtons::LEFT,
+            modifiers: TermwizModifiers::CTRL,
+        };
+        let expected = MouseEvent {
+            column: 5 - MOUSE_ORIGIN,
+            row: 10 - MOUSE_ORIGIN,
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::CTRL,
+        };
+        assert_eq!(MouseEvent::from(mouse_event), expected);
+    }
+
+    #[rstest]
+    #[case(InputEvent::Paste("hello".into()), Event::Paste("hello".into()))]
+    #[case(
+        InputEvent::Resized { cols: 80, rows: 24 },
+        Event::Resize { columns: 80, rows: 24 }
+    )]
+    #[case(
+        InputEvent::Resized { cols: 100_000, rows: 24 },
+        Event::Resize { columns: u16::MAX, rows: 24 }
+    )]
+    fn try_from_event(#[case] event: InputEvent, #[case] expected: Event) {
+        assert_eq!(Event::try_from(event), Ok(expected));
+    }
+
+    #[test]
+    fn try_from_unsupported_event() {
+        let error = Event::try_from(InputEvent::Wake).unwrap_err();
+        assert_eq!(
+            error,
+            ConversionError::UnsupportedEvent {
+                backend: "termwiz",
+                event: "Wake".into()
+            }
+        );
+        assert!(error.is_unsupported());
+        assert_eq!(Event::from_termwiz(InputEvent::Wake), Ok(None));
+    }
 }
@@ -0,0 +1,466 @@
+//! Keyboard focus management.
+//!
+//! Widgets that can receive keyboard focus implement [`Focusable`]. A [`FocusManager`] tracks
+//! which widget in a slice of widgets is focused, routes key events to that widget only, and moves
+//! focus with Tab and Shift+Tab. The manager does not own the widgets, so the same slice must be
+//! passed to each call.
+//!
+//! Widgets can be organized in groups. Trapping focus in a group (e.g. the buttons of a modal
+//! dialog) restricts traversal to that group until the trap is released, at which point focus
+//! returns to the widget that was focused before.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui_widgets::events::Key;
+//! use ratatui_widgets::focus::{FocusManager, Focusable};
+//! use ratatui_widgets::Button;
+//!
+//! let mut buttons = [Button::new("OK"), Button::new("Cancel")];
+//! let mut focus = FocusManager::new();
+//! focus.focus_next(&mut buttons);
+//! assert!(buttons[0].is_focused());
+//!
+//! focus.handle_key(&mut buttons, Key::Tab.into());
+//! assert!(buttons[1].is_focused());
+//! assert!(!buttons[0].is_focused());
+//! ```
+
+use crate::events::{Event, EventHandler, Key, KeyModifiers, KeyPressedEvent};
+use crate::keymap::Keymap;
+
+/// A widget that can receive keyboard focus
+pub trait Focusable: EventHandler {
+    fn is_focused(&self) -> bool;
+
+    fn set_focused(&mut self, focused: bool);
+
+    /// Returns whether the widget can currently receive focus. Widgets that are disabled or hidden
+    /// return `false` so that focus traversal skips them.
+    fn can_focus(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Focusable + ?Sized> Focusable for &mut T {
+    fn is_focused(&self) -> bool {
+        (**self).is_focused()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        (**self).set_focused(focused);
+    }
+
+    fn can_focus(&self) -> bool {
+        (**self).can_focus()
+    }
+}
+
+/// The actions that can be bound to keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// Focus the next widget, wrapping around at the end
+    Next,
+    /// Focus the previous widget, wrapping around at the start
+    Previous,
+}
+
+/// The default keymap: tab focuses the next widget and shift+tab the previous one
+pub fn default_keymap() -> Keymap<Action> {
+    let shift_back_tab = KeyPressedEvent {
+        key: Key::BackTab,
+        modifiers: KeyModifiers::SHIFT,
+    };
+    Keymap::new()
+        .with_binding([Key::Tab.into()], Action::Next)
+        .with_binding([Key::BackTab.into()], Action::Previous)
+        .with_binding([shift_back_tab], Action::Previous)
+}
+
+/// Identifies a group of widgets registered with a [`FocusManager`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FocusGroup(usize);
+
+/// Keeps track of the focused widget in a slice of widgets
+///
+/// Widgets are identified by their index in the slice, and the focus ring follows the slice order.
+#[derive(Debug, Clone)]
+pub struct FocusManager {
+    focused: Option<usize>,
+    groups: Vec<Vec<usize>>,
+    traps: Vec<Trap>,
+    keymap: Keymap<Action>,
+}
+
+#[derive(Debug, Clone)]
+struct Trap {
+    group: FocusGroup,
+    previously_focused: Option<usize>,
+}
+
+impl Default for FocusManager {
+    fn default() -> Self {
+        Self {
+            focused: None,
+            groups: Vec::new(),
+            traps: Vec::new(),
+            keymap: default_keymap(),
+        }
+    }
+}
+
+/// Config
+impl FocusManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_keymap(mut self, keymap: Keymap<Action>) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Registers a group of widgets by index. Within a trapped group, focus moves in the order the
+    /// indexes are given.
+    pub fn add_group<I: IntoIterator<Item = usize>>(&mut self, indexes: I) -> FocusGroup {
+        self.groups.push(indexes.into_iter().collect());
+        FocusGroup(self.groups.len() - 1)
+    }
+}
+
+impl FocusManager {
+    /// Returns the index of the focused widget.
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Returns the focused widget.
+    pub fn focused_mut<'a, W>(&self, widgets: &'a mut [W]) -> Option<&'a mut W> {
+        widgets.get_mut(self.focused?)
+    }
+
+    /// Focuses the widget at the given index, blurring the previously focused widget.
+    ///
+    /// Returns `false` without changing focus if there is no such widget, the widget cannot be
+    /// focused, or focus is trapped in a group that does not contain the widget.
+    pub fn focus<W: Focusable>(&mut self, widgets: &mut [W], index: usize) -> bool {
+        // `Option::is_none_or` needs Rust 1.82, which is newer than the MSRV
+        #[allow(clippy::unnecessary_map_or)]
+        let allowed = self
+            .trapped_group()
+            .map_or(true, |group| group.contains(&index));
+        if !allowed || !widgets.get(index).is_some_and(W::can_focus) {
+            return false;
+        }
+        if self.focused != Some(index) {
+            self.blur(widgets);
+        }
+        self.focused = Some(index);
+        widgets[index].set_focused(true);
+        true
+    }
+
+    /// Blurs the focused widget, leaving no widget focused.
+    pub fn blur<W: Focusable>(&mut self, widgets: &mut [W]) {
+        if let Some(widget) = self.focused.take().and_then(|index| widgets.get_mut(index)) {
+            widget.set_focused(false);
+        }
+    }
+
+    /// Focuses the next widget that can be focused, wrapping around at the end.
+    ///
+    /// Returns `false` if no widget can be focused.
+    pub fn focus_next<W: Focusable>(&mut self, widgets: &mut [W]) -> bool {
+        self.step(widgets, true)
+    }
+
+    /// Focuses the previous widget that can be focused, wrapping around at the start.
+    ///
+    /// Returns `false` if no widget can be focused.
+    pub fn focus_previous<W: Focusable>(&mut self, widgets: &mut [W]) -> bool {
+        self.step(widgets, false)
+    }
+
+    /// Focuses the first widget in the group that can be focused.
+    pub fn focus_group<W: Focusable>(&mut self, widgets: &mut [W], group: FocusGroup) -> bool {
+        let Some(members) = self.groups.get(group.0) else {
+            return false;
+        };
+        let first = members
+            .iter()
+            .copied()
+            .find(|&index| widgets.get(index).is_some_and(W::can_focus));
+        first.is_some_and(|index| self.focus(widgets, index))
+    }
+
+    /// Restricts focus to the widgets in the group, e.g. while a modal dialog is open, and focuses
+    /// the first widget in the group unless a widget in the group is already focused.
+    ///
+    /// Traps can be nested. Each trap is released with [`FocusManager::release_trap`].
+    pub fn trap<W: Focusable>(&mut self, widgets: &mut [W], group: FocusGroup) {
+        self.traps.push(Trap {
+            group,
+            previously_focused: self.focused,
+        });
+        let focused_in_group = self
+            .focused
+            .is_some_and(|index| self.trapped_group().is_some_and(|g| g.contains(&index)));
+        if !focused_in_group {
+            self.blur(widgets);
+            self.step(widgets, true);
+        }
+    }
+
+    /// Releases the most recent trap, returning focus to the widget that was focused when the
+    /// trap was set.
+    pub fn release_trap<W: Focusable>(&mut self, widgets: &mut [W]) -> Option<FocusGroup> {
+        let trap = self.traps.pop()?;
+        let restored = trap
+            .previously_focused
+            .is_some_and(|index| self.focus(widgets, index));
+        if !restored {
+            self.blur(widgets);
+        }
+        Some(trap.group)
+    }
+
+    /// Returns the group that focus is currently trapped in.
+    pub fn trapped(&self) -> Option<FocusGroup> {
+        self.traps.last().map(|trap| trap.group)
+    }
+
+    /// Handles a key press by moving focus if the key is bound to an [`Action`], and otherwise
+    /// passing it to the focused widget.
+    pub fn handle_key<W: Focusable>(&mut self, widgets: &mut [W], event: KeyPressedEvent) {
+        if !self.handle_action(widgets, event.clone()) {
+            if let Some(widget) = self.focused_mut(widgets) {
+                widget.handle_key(event);
+            }
+        }
+    }
+
+    /// Routes key and paste events to the focused widget, moving focus on the bound keys. Ticks
+    /// fire a bound sequence that has timed out. Other events are ignored.
+    pub fn handle_event<W: Focusable>(&mut self, widgets: &mut [W], event: Event) {
+        match event {
+            Event::KeyPressed(event) => self.handle_key(widgets, event),
+            Event::KeyRepeated(ref key) if self.handle_action(widgets, key.clone()) => {}
+            Event::KeyRepeated(_) | Event::KeyReleased(_) | Event::Paste(_) => {
+                if let Some(widget) = self.focused_mut(widgets) {
+                    widget.handle_event(event);
+                }
+            }
+            Event::Tick => {
+                if let Some(action) = self.keymap.expire() {
+                    self.perform(widgets, action);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_action<W: Focusable>(&mut self, widgets: &mut [W], event: KeyPressedEvent) -> bool {
+        let actions = self.keymap.resolve(event);
+        let handled = !actions.is_empty();
+        for action in actions {
+            self.perform(widgets, action);
+        }
+        handled
+    }
+
+    fn perform<W: Focusable>(&mut self, widgets: &mut [W], action: Action) {
+        match action {
+            Action::Next => self.focus_next(widgets),
+            Action::Previous => self.focus_previous(widgets),
+        };
+    }
+
+    fn trapped_group(&self) -> Option<&[usize]> {
+        let trap = self.traps.last()?;
+        Some(self.groups.get(trap.group.0).map_or(&[], Vec::as_slice))
+    }
+
+    fn step<W: Focusable>(&mut self, widgets: &mut [W], forward: bool) -> bool {
+        let candidates: Vec<usize> = match self.trapped_group() {
+            Some(group) => group.to_vec(),
+            None => (0..widgets.len()).collect(),
+        };
+        let count = candidates.len();
+        let current = self
+            .focused
+            .and_then(|focused| candidates.iter().position(|&index| index == focused));
+        for offset in 1..=count {
+            let position = match (current, forward) {
+                (Some(current), true) => (current + offset) % count,
+                (Some(current), false) => (current + count - offset % count) % count,
+                (None, true) => offset - 1,
+                (None, false) => count - offset,
+            };
+            if self.focus(widgets, candidates[position]) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::button::Button;
+
+    #[derive(Debug, Default)]
+    struct TestWidget {
+        focused: bool,
+        disabled: bool,
+        keys: Vec<Key>,
+    }
+
+    impl EventHandler for TestWidget {
+        fn handle_key(&mut self, event: KeyPressedEvent) {
+            self.keys.push(event.key);
+        }
+    }
+
+    impl Focusable for TestWidget {
+        fn is_focused(&self) -> bool {
+            self.focused
+        }
+
+        fn set_focused(&mut self, focused: bool) {
+            self.focused = focused;
+        }
+
+        fn can_focus(&self) -> bool {
+            !self.disabled
+        }
+    }
+
+    fn widgets(count: usize) -> Vec<TestWidget> {
+        (0..count).map(|_| TestWidget::default()).collect()
+    }
+
+    fn focused(widgets: &[TestWidget]) -> Vec<usize> {
+        (0..widgets.len()).filter(|&i| widgets[i].focused).collect()
+    }
+
+    #[rstest]
+    #[case::next_from_none(None, Key::Tab, Some(0))]
+    #[case::previous_from_none(None, Key::BackTab, Some(2))]
+    #[case::next(Some(0), Key::Tab, Some(1))]
+    #[case::next_wraps(Some(2), Key::Tab, Some(0))]
+    #[case::previous(Some(1), Key::BackTab, Some(0))]
+    #[case::previous_wraps(Some(0), Key::BackTab, Some(2))]
+    fn traversal(#[case] start: Option<usize>, #[case] key: Key, #[case] expected: Option<usize>) {
+        let mut widgets = widgets(3);
+        let mut focus = FocusManager::new();
+        if let Some(start) = start {
+            focus.focus(&mut widgets, start);
+        }
+        focus.handle_key(&mut widgets, key.into());
+        assert_eq!(focus.focused(), expected);
+        assert_eq!(focused(&widgets), Vec::from_iter(expected));
+    }
+
+    #[test]
+    fn traversal_skips_widgets_that_cannot_focus() {
+        let mut widgets = widgets(3);
+        widgets[1].disabled = true;
+        let mut focus = FocusManager::new();
+        focus.focus(&mut widgets, 0);
+        assert!(!focus.focus(&mut widgets, 1));
+        focus.focus_next(&mut widgets);
+        assert_eq!(focus.focused(), Some(2));
+
+        widgets[0].disabled = true;
+        widgets[2].disabled = true;
+        focus.blur(&mut widgets);
+        assert!(!focus.focus_next(&mut widgets));
+        assert_eq!(focus.focused(), None);
+    }
+
+    #[test]
+    fn routes_keys_to_focused_widget() {
+        let mut widgets = widgets(2);
+        let mut focus = FocusManager::new();
+        focus.handle_key(&mut widgets, Key::Char('a').into());
+        focus.focus(&mut widgets, 1);
+        focus.handle_key(&mut widgets, Key::Char('b').into());
+        focus.handle_event(&mut widgets, Event::KeyRepeated(Key::Char('c').into()));
+        focus.handle_event(&mut widgets, Event::KeyRepeated(Key::Tab.into()));
+        assert!(widgets[0].keys.is_empty());
+        assert_eq!(widgets[1].keys, [Key::Char('b'), Key::Char('c')]);
+        assert_eq!(focus.focused(), Some(0));
+    }
+
+    #[test]
+    fn custom_keymap() {
+        let mut widgets = widgets(2);
+        let keymap = Keymap::new().with_binding([Key::Right.into()], Action::Next);
+        let mut focus = FocusManager::new().with_keymap(keymap);
+        focus.handle_key(&mut widgets, Key::Right.into());
+        focus.handle_key(&mut widgets, Key::Tab.into());
+        assert_eq!(focus.focused(), Some(0));
+        assert_eq!(widgets[0].keys, [Key::Tab]);
+    }
+
+    #[test]
+    fn trap_restricts_traversal_and_restores_focus() {
+        let mut widgets = widgets(5);
+        let mut focus = FocusManager::new();
+        let dialog = focus.add_group([3, 4]);
+        focus.focus(&mut widgets, 1);
+
+        focus.trap(&mut widgets, dialog);
+        assert_eq!(focus.trapped(), Some(dialog));
+        assert_eq!(focused(&widgets), [3]);
+        focus.focus_next(&mut widgets);
+        assert_eq!(focus.focused(), Some(4));
+        focus.focus_next(&mut widgets);
+        assert_eq!(focus.focused(), Some(3));
+        assert!(!focus.focus(&mut widgets, 0));
+
+        assert_eq!(focus.release_trap(&mut widgets), Some(dialog));
+        assert_eq!(focus.trapped(), None);
+        assert_eq!(focused(&widgets), [1]);
+        assert_eq!(focus.release_trap(&mut widgets), None);
+    }
+
+    #[test]
+    fn focus_group() {
+        let mut widgets = widgets(4);
+        widgets[2].disabled = true;
+        let mut focus = FocusManager::new();
+        let group = focus.add_group([2, 3]);
+        assert!(focus.focus_group(&mut widgets, group));
+        assert_eq!(focus.focused(), Some(3));
+    }
+
+    #[test]
+    fn heterogeneous_widgets() {
+        let mut first = TestWidget::default();
+        let mut second = TestWidget::default();
+        let mut widgets: [&mut dyn Focusable; 2] = [&mut first, &mut second];
+        let mut focus = FocusManager::new();
+        focus.focus_previous(&mut widgets);
+        assert!(second.focused);
+        assert!(!first.focused);
+    }
+
+    #[test]
+    fn disabled_buttons_are_skipped() {
+        let mut buttons = [
+            Button::new("One"),
+            Button::new("Two").with_disabled(true),
+            Button::new("Three"),
+        ];
+        let mut focus = FocusManager::new();
+        focus.focus_next(&mut buttons);
+        focus.focus_next(&mut buttons);
+        assert_eq!(focus.focused(), Some(2));
+        assert!(!buttons[1].is_focused());
+    }
+}
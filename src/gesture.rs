@@ -0,0 +1,511 @@
+//! Recognition of higher level mouse gestures.
+//!
+//! A [`GestureRecognizer`] turns the raw mouse events into clicks, double and triple clicks, drags
+//! and hover changes. Together with a [`HitTest`], each gesture is attributed to the widget it
+//! applies to:
+//!
+//! - A click is reported when the mouse button is pressed and released over the same widget
+//!   without dragging. Clicks in the same position within the multi-click interval (500ms by
+//!   default) are reported as a [`Gesture::DoubleClick`] and then a [`Gesture::TripleClick`],
+//!   after which counting starts again.
+//! - A drag is reported to the widget it started on, even when the mouse leaves the widget.
+//! - Hover changes are reported when the mouse moves from one widget to another.
+//!
+//! Positions are in screen coordinates, as a drag can leave the widget it started on.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui::layout::{Position, Rect};
+//! use ratatui_widgets::events::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+//! use ratatui_widgets::gesture::{Gesture, GestureRecognizer};
+//! use ratatui_widgets::hit_test::HitTest;
+//!
+//! let mut hit_test = HitTest::new();
+//! hit_test.register(0, Rect::new(0, 0, 10, 1));
+//! let mut gestures = GestureRecognizer::new();
+//!
+//! let event = |kind| MouseEvent { column: 2, row: 0, kind, modifiers: KeyModifiers::empty() };
+//! gestures.recognize(event(MouseEventKind::Down(MouseButton::Left)), &hit_test);
+//! let recognized = gestures.recognize(event(MouseEventKind::Up(MouseButton::Left)), &hit_test);
+//! assert_eq!(recognized.last().unwrap().target, Some(0));
+//! assert_eq!(
+//!     recognized.last().unwrap().gesture,
+//!     Gesture::Click { button: MouseButton::Left, position: Position::new(2, 0) }
+//! );
+//! ```
+
+use std::time::{Duration, Instant};
+
+use ratatui::layout::Position;
+
+use crate::events::{MouseButton, MouseEvent, MouseEventKind};
+use crate::hit_test::HitTest;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gesture {
+    Click {
+        button: MouseButton,
+        position: Position,
+    },
+    DoubleClick {
+        button: MouseButton,
+        position: Position,
+    },
+    TripleClick {
+        button: MouseButton,
+        position: Position,
+    },
+    /// The mouse started moving with a button held down
+    DragStart {
+        button: MouseButton,
+        start: Position,
+        position: Position,
+    },
+    DragMove {
+        button: MouseButton,
+        start: Position,
+        position: Position,
+    },
+    /// The button was released after dragging
+    DragEnd {
+        button: MouseButton,
+        start: Position,
+        position: Position,
+    },
+    HoverEnter {
+        position: Position,
+    },
+    HoverLeave {
+        position: Position,
+    },
+}
+
+/// A gesture and the widget it applies to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GestureEvent<Id> {
+    /// The widget the gesture applies to, or `None` if there is no widget at the position
+    pub target: Option<Id>,
+    pub gesture: Gesture,
+}
+
+/// A widget that responds to gestures
+pub trait GestureHandler {
+    #[allow(unused_variables)]
+    fn handle_gesture(&mut self, gesture: Gesture) {}
+}
+
+impl<T: GestureHandler + ?Sized> GestureHandler for &mut T {
+    fn handle_gesture(&mut self, gesture: Gesture) {
+        (**self).handle_gesture(gesture);
+    }
+}
+
+/// Turns mouse events into gestures
+#[derive(Debug, Clone)]
+pub struct GestureRecognizer<Id = usize> {
+    multi_click_interval: Duration,
+    press: Option<Press<Id>>,
+    last_click: Option<LastClick>,
+    hovered: Option<Id>,
+}
+
+#[derive(Debug, Clone)]
+struct Press<Id> {
+    button: MouseButton,
+    start: Position,
+    target: Option<Id>,
+    dragging: bool,
+}
+
+#[derive(Debug, Clone)]
+struct LastClick {
+    button: MouseButton,
+    position: Position,
+    at: Instant,
+    count: u8,
+}
+
+impl<Id> Default for GestureRecognizer<Id> {
+    fn default() -> Self {
+        Self {
+            multi_click_interval: Duration::from_millis(500),
+            press: None,
+            last_click: None,
+            hovered: None,
+        }
+    }
+}
+
+/// Config
+impl<Id> GestureRecognizer<Id> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum time between clicks for them to count as a double or triple click.
+    pub fn with_multi_click_interval(mut self, interval: Duration) -> Self {
+        self.multi_click_interval = interval;
+        self
+    }
+}
+
+impl<Id: Clone + PartialEq> GestureRecognizer<Id> {
+    /// Returns the widget under the mouse.
+    pub fn hovered(&self) -> Option<&Id> {
+        self.hovered.as_ref()
+    }
+
+    /// Returns the gestures completed by the mouse event, in the order they happened.
+    pub fn recognize(
+        &mut self,
+        event: MouseEvent,
+        hit_test: &HitTest<Id>,
+    ) -> Vec<GestureEvent<Id>> {
+        self.recognize_at(event, hit_test, Instant::now())
+    }
+
+    /// Recognizes gestures for a mouse event that happened at the given time. This is mostly
+    /// useful for testing.
+    pub fn recognize_at(
+        &mut self,
+        event: MouseEvent,
+        hit_test: &HitTest<Id>,
+        now: Instant,
+    ) -> Vec<GestureEvent<Id>> {
+        let position = Position::new(event.column, event.row);
+        let target = hit_test.hit(position).map(|region| region.id.clone());
+        let mut gestures = Vec::new();
+
+        if target != self.hovered {
+            if let Some(previous) = self.hovered.take() {
+                gestures.push(GestureEvent {
+                    target: Some(previous),
+                    gesture: Gesture::HoverLeave { position },
+                });
+            }
+            if target.is_some() {
+                gestures.push(GestureEvent {
+                    target: target.clone(),
+                    gesture: Gesture::HoverEnter { position },
+                });
+            }
+            self.hovered.clone_from(&target);
+        }
+
+        match event.kind {
+            MouseEventKind::Down(button) => {
+                self.press = Some(Press {
+                    button,
+                    start: position,
+                    target,
+                    dragging: false,
+                });
+            }
+            MouseEventKind::Drag(button) => {
+                // some backends report a drag without reporting the press first
+                let press = self.press.get_or_insert_with(|| Press {
+                    button,
+                    start: position,
+                    target,
+                    dragging: false,
+                });
+                let start = press.start;
+                if !press.dragging {
+                    press.dragging = true;
+                    gestures.push(GestureEvent {
+                        target: press.target.clone(),
+                        gesture: Gesture::DragStart {
+                            button,
+                            start,
+                            position,
+                        },
+                    });
+                }
+                gestures.push(GestureEvent {
+                    target: press.target.clone(),
+                    gesture: Gesture::DragMove {
+                        button,
+                        start,
+                        position,
+                    },
+                });
+            }
+            MouseEventKind::Up(button) => match self.press.take() {
+                Some(press) if press.dragging => gestures.push(GestureEvent {
+                    target: press.target,
+                    gesture: Gesture::DragEnd {
+                        button,
+                        start: press.start,
+                        position,
+                    },
+                }),
+                Some(press) if press.button == button && press.target == target => {
+                    let gesture = match self.click_count(button, position, now) {
+                        1 => Gesture::Click { button, position },
+                        2 => Gesture::DoubleClick { button, position },
+                        _ => Gesture::TripleClick { button, position },
+                    };
+                    gestures.push(GestureEvent { target, gesture });
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+        gestures
+    }
+
+    fn click_count(&mut self, button: MouseButton, position: Position, now: Instant) -> u8 {
+        let count = match &self.last_click {
+            Some(last)
+                if last.button == button
+                    && last.position == position
+                    && last.count < 3
+                    && now.saturating_duration_since(last.at) <= self.multi_click_interval =>
+            {
+                last.count + 1
+            }
+            _ => 1,
+        };
+        self.last_click = Some(LastClick {
+            button,
+            position,
+            at: now,
+            count,
+        });
+        count
+    }
+}
+
+impl GestureRecognizer<usize> {
+    /// Recognizes the gestures for the mouse event and passes each one to its target, using the
+    /// ids registered in the hit test as indexes into `widgets`.
+    pub fn dispatch<W: GestureHandler>(
+        &mut self,
+        widgets: &mut [W],
+        hit_test: &HitTest,
+        event: MouseEvent,
+    ) -> Vec<GestureEvent<usize>> {
+        let gestures = self.recognize(event, hit_test);
+        for event in &gestures {
+            if let Some(widget) = event.target.and_then(|index| widgets.get_mut(index)) {
+                widget.handle_gesture(event.gesture);
+            }
+        }
+        gestures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::events::KeyModifiers;
+
+    const LEFT: MouseButton = MouseButton::Left;
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            column,
+            row,
+            kind,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+
+    #[fixture]
+    fn hit_test() -> HitTest<&'static str> {
+        let mut hit_test = HitTest::new();
+        hit_test.register("a", Rect::new(0, 0, 5, 1));
+        hit_test.register("b", Rect::new(5, 0, 5, 1));
+        hit_test
+    }
+
+    fn gestures(events: &[GestureEvent<&'static str>]) -> Vec<Gesture> {
+        events.iter().map(|event| event.gesture).collect()
+    }
+
+    /// Clicks at (1, 0) at the given offsets in milliseconds from the first click
+    fn clicks(hit_test: &HitTest<&'static str>, offsets: &[u64]) -> Vec<Gesture> {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+        let mut recognized = vec![];
+        for &offset in offsets {
+            let at = start + Duration::from_millis(offset);
+            recognizer.recognize_at(mouse(MouseEventKind::Down(LEFT), 1, 0), hit_test, at);
+            let events =
+                recognizer.recognize_at(mouse(MouseEventKind::Up(LEFT), 1, 0), hit_test, at);
+            recognized.extend(gestures(&events));
+        }
+        recognized.retain(|gesture| !matches!(gesture, Gesture::HoverEnter { .. }));
+        recognized
+    }
+
+    #[rstest]
+    fn multi_click(hit_test: HitTest<&'static str>) {
+        let position = Position::new(1, 0);
+        assert_eq!(
+            clicks(&hit_test, &[0, 100, 200, 300]),
+            [
+                Gesture::Click {
+                    button: LEFT,
+                    position
+                },
+                Gesture::DoubleClick {
+                    button: LEFT,
+                    position
+                },
+                Gesture::TripleClick {
+                    button: LEFT,
+                    position
+                },
+                Gesture::Click {
+                    button: LEFT,
+                    position
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn multi_click_interval_expired(hit_test: HitTest<&'static str>) {
+        let position = Position::new(1, 0);
+        assert_eq!(
+            clicks(&hit_test, &[0, 600]),
+            [
+                Gesture::Click {
+                    button: LEFT,
+                    position
+                },
+                Gesture::Click {
+                    button: LEFT,
+                    position
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn release_over_other_widget_is_not_a_click(hit_test: HitTest<&'static str>) {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.recognize(mouse(MouseEventKind::Down(LEFT), 1, 0), &hit_test);
+        let events = recognizer.recognize(mouse(MouseEventKind::Up(LEFT), 6, 0), &hit_test);
+        let position = Position::new(6, 0);
+        assert_eq!(
+            events,
+            [
+                GestureEvent {
+                    target: Some("a"),
+                    gesture: Gesture::HoverLeave { position }
+                },
+                GestureEvent {
+                    target: Some("b"),
+                    gesture: Gesture::HoverEnter { position }
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn drag(hit_test: HitTest<&'static str>) {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Position::new(1, 0);
+        let mut recognized = vec![];
+        for event in [
+            mouse(MouseEventKind::Down(LEFT), 1, 0),
+            mouse(MouseEventKind::Drag(LEFT), 2, 0),
+            mouse(MouseEventKind::Drag(LEFT), 3, 2),
+            mouse(MouseEventKind::Up(LEFT), 3, 2),
+        ] {
+            recognized.extend(recognizer.recognize(event, &hit_test));
+        }
+        let drag = |gesture| GestureEvent {
+            target: Some("a"),
+            gesture,
+        };
+        assert_eq!(
+            recognized,
+            [
+                drag(Gesture::HoverEnter { position: start }),
+                drag(Gesture::DragStart {
+                    button: LEFT,
+                    start,
+                    position: Position::new(2, 0)
+                }),
+                drag(Gesture::DragMove {
+                    button: LEFT,
+                    start,
+                    position: Position::new(2, 0)
+                }),
+                drag(Gesture::HoverLeave {
+                    position: Position::new(3, 2)
+                }),
+                drag(Gesture::DragMove {
+                    button: LEFT,
+                    start,
+                    position: Position::new(3, 2)
+                }),
+                drag(Gesture::DragEnd {
+                    button: LEFT,
+                    start,
+                    position: Position::new(3, 2)
+                }),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn hover(hit_test: HitTest<&'static str>) {
+        let mut recognizer = GestureRecognizer::new();
+        let moved = |column| mouse(MouseEventKind::Moved, column, 0);
+
+        let events = recognizer.recognize(moved(1), &hit_test);
+        assert_eq!(events[0].target, Some("a"));
+        assert_eq!(recognizer.hovered(), Some(&"a"));
+        assert!(recognizer.recognize(moved(2), &hit_test).is_empty());
+
+        let events = recognizer.recognize(moved(20), &hit_test);
+        assert_eq!(
+            events,
+            [GestureEvent {
+                target: Some("a"),
+                gesture: Gesture::HoverLeave {
+                    position: Position::new(20, 0)
+                }
+            }]
+        );
+        assert_eq!(recognizer.hovered(), None);
+    }
+
+    #[derive(Debug, Default)]
+    struct GestureRecorder {
+        gestures: Vec<Gesture>,
+    }
+
+    impl GestureHandler for GestureRecorder {
+        fn handle_gesture(&mut self, gesture: Gesture) {
+            self.gestures.push(gesture);
+        }
+    }
+
+    #[test]
+    fn dispatch() {
+        let mut widgets = [GestureRecorder::default(), GestureRecorder::default()];
+        let mut hit_test = HitTest::new();
+        hit_test.register(0, Rect::new(0, 0, 5, 1));
+        hit_test.register(1, Rect::new(5, 0, 5, 1));
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.dispatch(&mut widgets, &hit_test, mouse(MouseEventKind::Moved, 6, 0));
+        assert!(widgets[0].gestures.is_empty());
+        assert_eq!(
+            widgets[1].gestures,
+            [Gesture::HoverEnter {
+                position: Position::new(6, 0)
+            }]
+        );
+    }
+}
@@ -0,0 +1,195 @@
+//! Mouse hit testing by rendered area.
+//!
+//! A [`HitTest`] records the area each widget was rendered to. Containers clear it at the start of
+//! each render and register their children as they render them. Mouse events are then routed to
+//! the widget under the cursor, with the coordinates translated so that the top left corner of the
+//! widget's area is `(0, 0)`.
+//!
+//! Overlapping areas are resolved by z-index, and then by registration order, so that a widget
+//! rendered later (on top) wins.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui::layout::Rect;
+//! use ratatui_widgets::events::{MouseButton, MouseEvent, MouseEventKind, KeyModifiers};
+//! use ratatui_widgets::hit_test::HitTest;
+//!
+//! let mut hit_test = HitTest::new();
+//! hit_test.register("background", Rect::new(0, 0, 80, 24));
+//! hit_test.register_with_z_index("popup", Rect::new(10, 5, 20, 5), 1);
+//!
+//! let click = MouseEvent {
+//!     column: 12,
+//!     row: 6,
+//!     kind: MouseEventKind::Down(MouseButton::Left),
+//!     modifiers: KeyModifiers::empty(),
+//! };
+//! let (id, local) = hit_test.route(click).unwrap();
+//! assert_eq!(*id, "popup");
+//! assert_eq!((local.column, local.row), (2, 1));
+//! ```
+
+use ratatui::layout::{Position, Rect};
+
+use crate::events::{EventHandler, MouseEvent};
+
+/// The area a widget was rendered to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Region<Id> {
+    pub id: Id,
+    pub area: Rect,
+    pub z_index: u16,
+}
+
+/// Records the areas widgets were rendered to, for routing mouse events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HitTest<Id = usize> {
+    regions: Vec<Region<Id>>,
+}
+
+impl<Id> Default for HitTest<Id> {
+    fn default() -> Self {
+        Self {
+            regions: Vec::new(),
+        }
+    }
+}
+
+impl<Id> HitTest<Id> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all registered regions. Call this before rendering the widgets again.
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    /// Registers the area a widget was rendered to, with a z-index of 0.
+    pub fn register(&mut self, id: Id, area: Rect) {
+        self.register_with_z_index(id, area, 0);
+    }
+
+    /// Registers the area a widget was rendered to. Regions with a higher z-index are on top.
+    pub fn register_with_z_index(&mut self, id: Id, area: Rect, z_index: u16) {
+        self.regions.push(Region { id, area, z_index });
+    }
+
+    /// Returns the registered regions in the order they were registered.
+    pub fn regions(&self) -> &[Region<Id>] {
+        &self.regions
+    }
+
+    /// Returns the topmost region that contains the position.
+    pub fn hit(&self, position: Position) -> Option<&Region<Id>> {
+        self.regions
+            .iter()
+            .enumerate()
+            .filter(|(_, region)| region.area.contains(position))
+            .max_by_key(|(order, region)| (region.z_index, *order))
+            .map(|(_, region)| region)
+    }
+
+    /// Returns the id of the widget under the mouse and the event translated to the widget's local
+    /// coordinates.
+    pub fn route(&self, event: MouseEvent) -> Option<(&Id, MouseEvent)> {
+        let region = self.hit(Position::new(event.column, event.row))?;
+        let local = MouseEvent {
+            column: event.column - region.area.x,
+            row: event.row - region.area.y,
+            ..event
+        };
+        Some((&region.id, local))
+    }
+}
+
+impl HitTest<usize> {
+    /// Passes the event, in local coordinates, to the widget under the mouse, using the
+    /// registered ids as indexes into `widgets`.
+    ///
+    /// Returns the index of the widget that handled the event.
+    pub fn dispatch<W: EventHandler>(&self, widgets: &mut [W], event: MouseEvent) -> Option<usize> {
+        let (&index, local) = self.route(event)?;
+        widgets.get_mut(index)?.handle_mouse(local);
+        Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::events::{KeyModifiers, MouseButton, MouseEventKind};
+
+    fn mouse_down(column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            column,
+            row,
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+
+    fn hit_test() -> HitTest<&'static str> {
+        let mut hit_test = HitTest::new();
+        hit_test.register("left", Rect::new(0, 0, 10, 10));
+        hit_test.register("right", Rect::new(10, 0, 10, 10));
+        hit_test.register_with_z_index("popup", Rect::new(5, 2, 10, 3), 1);
+        hit_test.register("overlap", Rect::new(15, 5, 10, 10));
+        hit_test
+    }
+
+    #[rstest]
+    #[case::left(Position::new(0, 0), Some("left"))]
+    #[case::right(Position::new(19, 0), Some("right"))]
+    #[case::higher_z_index(Position::new(5, 2), Some("popup"))]
+    #[case::registered_later(Position::new(15, 5), Some("overlap"))]
+    #[case::outside(Position::new(0, 10), None)]
+    fn hit(#[case] position: Position, #[case] expected: Option<&str>) {
+        let hit_test = hit_test();
+        assert_eq!(hit_test.hit(position).map(|region| region.id), expected);
+    }
+
+    #[test]
+    fn route_translates_coordinates() {
+        let hit_test = hit_test();
+        let (id, local) = hit_test.route(mouse_down(12, 3)).unwrap();
+        assert_eq!(*id, "popup");
+        assert_eq!(local, mouse_down(7, 1));
+        assert_eq!(hit_test.route(mouse_down(30, 30)), None);
+    }
+
+    #[test]
+    fn clear() {
+        let mut hit_test = hit_test();
+        hit_test.clear();
+        assert!(hit_test.regions().is_empty());
+        assert_eq!(hit_test.hit(Position::new(0, 0)), None);
+    }
+
+    #[derive(Debug, Default)]
+    struct MouseRecorder {
+        events: Vec<MouseEvent>,
+    }
+
+    impl EventHandler for MouseRecorder {
+        fn handle_mouse(&mut self, event: MouseEvent) {
+            self.events.push(event);
+        }
+    }
+
+    #[test]
+    fn dispatch() {
+        let mut widgets = [MouseRecorder::default(), MouseRecorder::default()];
+        let mut hit_test = HitTest::new();
+        hit_test.register(0, Rect::new(0, 0, 5, 1));
+        hit_test.register(1, Rect::new(5, 0, 5, 1));
+
+        assert_eq!(hit_test.dispatch(&mut widgets, mouse_down(7, 0)), Some(1));
+        assert_eq!(hit_test.dispatch(&mut widgets, mouse_down(7, 1)), None);
+        assert!(widgets[0].events.is_empty());
+        assert_eq!(widgets[1].events, [mouse_down(2, 0)]);
+    }
+}
@@ -0,0 +1,443 @@
+//! Keymaps that map key chords and sequences to actions.
+//!
+//! Each widget that handles keys defines an `Action` enum and a `default_keymap()` function, and
+//! accepts a replacement keymap with `with_keymap()`. This makes it possible to provide vim, emacs
+//! or arrow-only bindings without changing the widget.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui_widgets::events::{Key, KeyPressedEvent};
+//! use ratatui_widgets::keymap::Keymap;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Action {
+//!     Top,
+//!     Save,
+//! }
+//!
+//! let mut keymap = Keymap::new()
+//!     .with_sequence("g g", Action::Top)
+//!     .unwrap()
+//!     .with_sequence("ctrl+s", Action::Save)
+//!     .unwrap();
+//!
+//! let g = KeyPressedEvent::from(Key::Char('g'));
+//! assert_eq!(keymap.resolve(g.clone()), []);
+//! assert_eq!(keymap.resolve(g), [Action::Top]);
+//! assert_eq!(keymap.resolve("ctrl+s".parse().unwrap()), [Action::Save]);
+//! ```
+
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+
+use crate::events::{KeyPressedEvent, ParseKeyError};
+
+/// Resolves key presses to actions.
+///
+/// A binding is a sequence of one or more key chords. When the keys pressed so far are the start
+/// of a longer sequence, the keymap waits for the next key. If it does not arrive within the
+/// timeout (one second by default), the partial sequence is discarded.
+///
+/// A binding that is also the start of a longer one, such as `g` when `g g` is bound too, waits
+/// for the next key like any other partial sequence. It fires when the next key does not continue
+/// the longer sequence, along with the action of that key, or from [`Keymap::expire`] (which
+/// widgets call on each tick) once the timeout passes.
+#[derive(Debug, Clone)]
+pub struct Keymap<A> {
+    bindings: Vec<(Vec<KeyPressedEvent>, A)>,
+    timeout: Duration,
+    pending: Vec<KeyPressedEvent>,
+    last_key_at: Option<Instant>,
+}
+
+impl<A> Default for Keymap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            timeout: Duration::from_secs(1),
+            pending: Vec::new(),
+            last_key_at: None,
+        }
+    }
+}
+
+/// Config
+impl<A> Keymap<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_binding<K>(mut self, keys: K, action: A) -> Self
+    where
+        K: IntoIterator<Item = KeyPressedEvent>,
+    {
+        self.bind(keys, action);
+        self
+    }
+
+    /// Binds a sequence written as whitespace separated key chords, e.g. `"g g"` or `"ctrl+x s"`.
+    pub fn with_sequence(mut self, sequence: &str, action: A) -> Result<Self, ParseKeyError> {
+        self.bind(parse_sequence(sequence)?, action);
+        Ok(self)
+    }
+
+    /// Binds a sequence of keys to an action, replacing any existing binding for the sequence.
+    pub fn bind<K>(&mut self, keys: K, action: A)
+    where
+        K: IntoIterator<Item = KeyPressedEvent>,
+    {
+        let keys = keys.into_iter().collect_vec();
+        self.unbind(keys.clone());
+        self.bindings.push((keys, action));
+    }
+
+    /// Removes the binding for a sequence of keys.
+    pub fn unbind<K>(&mut self, keys: K)
+    where
+        K: IntoIterator<Item = KeyPressedEvent>,
+    {
+        let keys = keys.into_iter().collect_vec();
+        self.bindings.retain(|(bound, _)| *bound != keys);
+    }
+
+    /// Returns the bound sequences and their actions, in the order they were bound.
+    pub fn bindings(&self) -> impl Iterator<Item = (&[KeyPressedEvent], &A)> {
+        self.bindings
+            .iter()
+            .map(|(keys, action)| (keys.as_slice(), action))
+    }
+}
+
+impl<A: Clone> Keymap<A> {
+    /// Resolves a key press to the actions it fires.
+    ///
+    /// Returns no actions if the key is unbound or is part of an incomplete sequence. When the key
+    /// interrupts a sequence whose keys so far are bound themselves, that binding's action is
+    /// returned before the action of the key.
+    pub fn resolve(&mut self, key: KeyPressedEvent) -> Vec<A> {
+        self.resolve_at(key, Instant::now())
+    }
+
+    /// Resolves a key press that happened at the given time. This is mostly useful for testing.
+    pub fn resolve_at(&mut self, key: KeyPressedEvent, now: Instant) -> Vec<A> {
+        let mut actions = Vec::new();
+        if self.is_expired(now) {
+            actions.extend(self.take_pending());
+        }
+        self.last_key_at = Some(now);
+        self.pending.push(key.clone());
+
+        let mut complete = None;
+        let mut is_prefix = false;
+        for (keys, action) in &self.bindings {
+            if keys == &self.pending {
+                complete = Some(action);
+            } else {
+                is_prefix |= keys.starts_with(&self.pending);
+            }
+        }
+        if is_prefix {
+            return actions;
+        }
+        if let Some(action) = complete {
+            self.pending.clear();
+            actions.push(action.clone());
+            return actions;
+        }
+        // the sequence can't be completed, so fire the keys before this one if they are bound, and
+        // start a new sequence from this key
+        if self.pending.len() > 1 {
+            self.pending.pop();
+            actions.extend(self.take_pending());
+            actions.extend(self.resolve_at(key, now));
+        } else {
+            self.pending.clear();
+        }
+        actions
+    }
+
+    /// Discards the pending sequence once the timeout has passed, returning the action bound to
+    /// the keys pressed so far, if any.
+    ///
+    /// Call this on each tick so that a binding which is also the start of a longer one fires when
+    /// no further key is pressed.
+    pub fn expire(&mut self) -> Option<A> {
+        self.expire_at(Instant::now())
+    }
+
+    /// Expires the pending sequence as if at the given time. This is mostly useful for testing.
+    pub fn expire_at(&mut self, now: Instant) -> Option<A> {
+        if !self.is_expired(now) {
+            return None;
+        }
+        self.last_key_at = None;
+        self.take_pending()
+    }
+
+    /// Discards the pending sequence, returning the action bound to it, if any.
+    fn take_pending(&mut self) -> Option<A> {
+        let keys = std::mem::take(&mut self.pending);
+        self.action(keys).cloned()
+    }
+
+    fn is_expired(&self, now: Instant) -> bool {
+        !self.pending.is_empty()
+            && self
+                .last_key_at
+                .is_some_and(|last| now.saturating_duration_since(last) > self.timeout)
+    }
+
+    /// Returns the action bound to a sequence of keys, without affecting any pending sequence.
+    pub fn action<K>(&self, keys: K) -> Option<&A>
+    where
+        K: IntoIterator<Item = KeyPressedEvent>,
+    {
+        let keys = keys.into_iter().collect_vec();
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == keys)
+            .map(|(_, action)| action)
+    }
+
+    /// Returns the keys of the incomplete sequence that is waiting for more keys.
+    pub fn pending(&self) -> &[KeyPressedEvent] {
+        &self.pending
+    }
+
+    /// Discards any incomplete sequence.
+    pub fn clear_pending(&mut self) {
+        self.pending.clear();
+        self.last_key_at = None;
+    }
+}
+
+/// Parses a sequence of whitespace separated key chords, e.g. `"g g"` or `"ctrl+x s"`.
+pub fn parse_sequence(sequence: &str) -> Result<Vec<KeyPressedEvent>, ParseKeyError> {
+    let keys: Vec<KeyPressedEvent> = sequence.split_whitespace().map(str::parse).try_collect()?;
+    if keys.is_empty() {
+        return Err(ParseKeyError::Empty);
+    }
+    Ok(keys)
+}
+
+/// Formats a sequence of key chords as whitespace separated chords, the inverse of
+/// [`parse_sequence`].
+pub fn format_sequence(keys: &[KeyPressedEvent]) -> String {
+    keys.iter().join(" ")
+}
+
+/// Serializes the bindings as a map from whitespace separated key chords to actions, e.g.
+/// `{ "g g": "Top", "Ctrl+s": "Save" }`. The timeout is not serialized.
+#[cfg(feature = "serde")]
+impl<A: serde::Serialize> serde::Serialize for Keymap<A> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(self.bindings.len()))?;
+        for (keys, action) in &self.bindings {
+            map.serialize_entry(&format_sequence(keys), action)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A: serde::Deserialize<'de>> serde::Deserialize<'de> for Keymap<A> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KeymapVisitor<A>(std::marker::PhantomData<A>);
+
+        impl<'de, A: serde::Deserialize<'de>> serde::de::Visitor<'de> for KeymapVisitor<A> {
+            type Value = Keymap<A>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a map from key sequences to actions")
+            }
+
+            fn visit_map<M: serde::de::MapAccess<'de>>(
+                self,
+                mut map: M,
+            ) -> Result<Self::Value, M::Error> {
+                let mut keymap = Keymap::new();
+                while let Some((sequence, action)) = map.next_entry::<String, A>()? {
+                    let keys = parse_sequence(&sequence).map_err(serde::de::Error::custom)?;
+                    keymap.bind(keys, action);
+                }
+                Ok(keymap)
+            }
+        }
+
+        deserializer.deserialize_map(KeymapVisitor(std::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Key, KeyModifiers};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum Action {
+        Quit,
+        Top,
+        Save,
+        Down,
+    }
+
+    fn key(c: char) -> KeyPressedEvent {
+        KeyPressedEvent::from(Key::Char(c))
+    }
+
+    fn keymap() -> Keymap<Action> {
+        Keymap::new()
+            .with_binding([key('q')], Action::Quit)
+            .with_binding([key('g'), key('g')], Action::Top)
+            .with_sequence("ctrl+x ctrl+s", Action::Save)
+            .unwrap()
+            .with_binding([Key::Down.into()], Action::Down)
+    }
+
+    #[test]
+    fn resolve_single_key() {
+        let mut keymap = keymap();
+        assert_eq!(keymap.resolve(key('q')), [Action::Quit]);
+        assert_eq!(keymap.resolve(Key::Down.into()), [Action::Down]);
+        assert_eq!(keymap.resolve(key('x')), []);
+    }
+
+    #[test]
+    fn resolve_sequence() {
+        let mut keymap = keymap();
+        assert_eq!(keymap.resolve(key('g')), []);
+        assert_eq!(keymap.pending(), [key('g')]);
+        assert_eq!(keymap.resolve(key('g')), [Action::Top]);
+        assert_eq!(keymap.pending(), []);
+
+        let ctrl = |c| KeyPressedEvent {
+            key: Key::Char(c),
+            modifiers: KeyModifiers::CTRL,
+        };
+        assert_eq!(keymap.resolve(ctrl('x')), []);
+        assert_eq!(keymap.resolve(ctrl('s')), [Action::Save]);
+    }
+
+    #[test]
+    fn resolve_sequence_timeout() {
+        let mut keymap = keymap().with_timeout(Duration::from_millis(500));
+        let start = Instant::now();
+        assert_eq!(keymap.resolve_at(key('g'), start), []);
+        let later = start + Duration::from_millis(600);
+        assert_eq!(keymap.resolve_at(key('g'), later), []);
+        assert_eq!(keymap.pending(), [key('g')]);
+        let soon_after = later + Duration::from_millis(100);
+        assert_eq!(keymap.resolve_at(key('g'), soon_after), [Action::Top]);
+    }
+
+    #[test]
+    fn resolve_shorter_binding_on_timeout() {
+        let mut keymap = keymap()
+            .with_timeout(Duration::from_millis(500))
+            .with_binding([key('g')], Action::Down);
+        let start = Instant::now();
+        assert_eq!(keymap.resolve_at(key('g'), start), []);
+        let soon_after = start + Duration::from_millis(100);
+        assert_eq!(keymap.expire_at(soon_after), None);
+        assert_eq!(keymap.resolve_at(key('g'), soon_after), [Action::Top]);
+
+        assert_eq!(keymap.resolve_at(key('g'), start), []);
+        assert_eq!(keymap.expire_at(soon_after), None);
+        let later = start + Duration::from_millis(600);
+        assert_eq!(keymap.expire_at(later), Some(Action::Down));
+        assert_eq!(keymap.pending(), []);
+        assert_eq!(keymap.expire_at(later), None);
+    }
+
+    #[test]
+    fn resolve_shorter_binding_when_interrupted() {
+        let mut keymap = keymap().with_binding([key('g')], Action::Down);
+        assert_eq!(keymap.resolve(key('g')), []);
+        assert_eq!(keymap.resolve(key('q')), [Action::Down, Action::Quit]);
+        assert_eq!(keymap.pending(), []);
+
+        // the interrupting key can start a sequence of its own
+        assert_eq!(keymap.resolve(key('g')), []);
+        assert_eq!(keymap.resolve(key('x')), [Action::Down]);
+        assert_eq!(keymap.resolve(key('g')), []);
+        assert_eq!(keymap.pending(), [key('g')]);
+    }
+
+    #[test]
+    fn resolve_shorter_binding_after_timeout_without_tick() {
+        let mut keymap = keymap()
+            .with_timeout(Duration::from_millis(500))
+            .with_binding([key('g')], Action::Down);
+        let start = Instant::now();
+        assert_eq!(keymap.resolve_at(key('g'), start), []);
+        let later = start + Duration::from_millis(600);
+        assert_eq!(
+            keymap.resolve_at(key('q'), later),
+            [Action::Down, Action::Quit]
+        );
+
+        assert_eq!(keymap.resolve_at(key('g'), start), []);
+        assert_eq!(keymap.resolve_at(key('g'), later), [Action::Down]);
+        assert_eq!(keymap.pending(), [key('g')]);
+    }
+
+    #[test]
+    fn resolve_broken_sequence_restarts_from_key() {
+        let mut keymap = keymap();
+        assert_eq!(keymap.resolve(key('g')), []);
+        assert_eq!(keymap.resolve(key('q')), [Action::Quit]);
+        assert_eq!(keymap.pending(), []);
+    }
+
+    #[test]
+    fn bind_replaces_existing_binding() {
+        let mut keymap = keymap();
+        keymap.bind([key('q')], Action::Save);
+        assert_eq!(keymap.action([key('q')]), Some(&Action::Save));
+        assert_eq!(keymap.bindings().count(), 4);
+        keymap.unbind([key('q')]);
+        assert_eq!(keymap.action([key('q')]), None);
+    }
+
+    #[test]
+    fn parse_and_format_sequence() {
+        let keys = parse_sequence("ctrl+x  s").unwrap();
+        assert_eq!(
+            keys,
+            [
+                KeyPressedEvent {
+                    key: Key::Char('x'),
+                    modifiers: KeyModifiers::CTRL
+                },
+                key('s')
+            ]
+        );
+        assert_eq!(format_sequence(&keys), "Ctrl+x s");
+        assert_eq!(parse_sequence(" "), Err(ParseKeyError::Empty));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let json = serde_json::to_string(&keymap()).unwrap();
+        assert_eq!(
+            json,
+            r#"{"q":"Quit","g g":"Top","Ctrl+x Ctrl+s":"Save","Down":"Down"}"#
+        );
+        let mut keymap: Keymap<Action> = serde_json::from_str(&json).unwrap();
+        assert_eq!(keymap.resolve(key('g')), []);
+        assert_eq!(keymap.resolve(key('g')), [Action::Top]);
+
+        let error = serde_json::from_str::<Keymap<Action>>(r#"{"ctrl+nope":"Quit"}"#).unwrap_err();
+        assert!(error.to_string().contains("Unknown key: nope"), "{error}");
+    }
+}
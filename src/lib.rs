@@ -45,16 +45,41 @@
 //!
 //! [Ratatui]: https://crates.io/crates/ratatui
 
+// The public modules are deprecated for downstream users, but the crate itself still uses them.
+#![allow(deprecated)]
+
 #[deprecated(note = "Use tui-framework-experiment instead")]
 pub mod button;
 #[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod button_group;
+#[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod color;
+#[deprecated(note = "Use tui-framework-experiment instead")]
 pub mod events;
 #[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod focus;
+#[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod gesture;
+#[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod hit_test;
+#[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod keymap;
+#[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod recording;
+#[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod runner;
+#[deprecated(note = "Use tui-framework-experiment instead")]
 pub mod stack_container;
 #[deprecated(note = "Use tui-framework-experiment instead")]
+pub mod theme;
+#[deprecated(note = "Use tui-framework-experiment instead")]
 pub mod toggle_switch;
 
 #[deprecated(note = "Use tui-framework-experiment instead")]
-pub use button::{Button, State as ButtonState, Theme as ButtonTheme};
+pub use button::{
+    Button, ButtonStyle, Mode as ButtonMode, State as ButtonState, Theme as ButtonTheme,
+};
+#[deprecated(note = "Use tui-framework-experiment instead")]
+pub use button_group::ButtonGroup;
 #[deprecated(note = "Use tui-framework-experiment instead")]
 pub use stack_container::StackContainer;
@@ -0,0 +1,421 @@
+//! Event recording and deterministic replay.
+//!
+//! A [`Recorder`] wraps any [`EventHandler`] and logs each event it handles along with the time
+//! since recording started. A [`Player`] feeds the recorded events back into a handler, and a
+//! [`ReplayTerminal`] renders after each step so that the result can be compared against expected
+//! buffers. With the `serde` feature enabled, recordings can be saved to a file from a bug report
+//! session and replayed in a test.
+//!
+//! Replay ignores the recorded times and feeds the events in as fast as they are handled. Widgets
+//! that count [`Event::Tick`]s replay exactly, but behavior that reads the clock, such as double
+//! clicks and the timeout of key sequences, may differ from the recorded session.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui::{prelude::*, widgets::Widget};
+//! use ratatui_widgets::events::{Event, EventHandler, Key};
+//! use ratatui_widgets::recording::{Recorder, ReplayTerminal};
+//! use ratatui_widgets::Button;
+//!
+//! let mut recorder = Recorder::new(Button::new("OK"));
+//! recorder.handle_event(Event::KeyPressed(Key::Enter.into()));
+//! let (_, events) = recorder.into_parts();
+//!
+//! let mut terminal = ReplayTerminal::new(10, 3);
+//! let mut button = Button::new("OK");
+//! let buffers = terminal.replay(&mut button, &events, |button, area, buf| {
+//!     button.render(area, buf)
+//! });
+//! assert_eq!(buffers.len(), 1);
+//! ```
+
+use std::time::{Duration, Instant};
+
+use ratatui::{backend::TestBackend, buffer::Buffer, layout::Rect, Terminal};
+
+use crate::events::{Event, EventHandler, KeyPressedEvent, KeyReleasedEvent, MouseEvent, TimerId};
+#[cfg(feature = "theme-file")]
+use crate::theme::file::ThemeFile;
+
+/// An event along with the time since recording started
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedEvent {
+    pub at: Duration,
+    pub event: Event,
+}
+
+/// Records the events handled by a wrapped [`EventHandler`]
+///
+/// Events are recorded before being passed on to the wrapped handler, whether they arrive through
+/// [`EventHandler::handle_event`] or one of the specific handler methods.
+#[derive(Debug)]
+pub struct Recorder<H> {
+    handler: H,
+    started_at: Instant,
+    events: Vec<RecordedEvent>,
+}
+
+impl<H: EventHandler> Recorder<H> {
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            started_at: Instant::now(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Stops recording, returning the wrapped handler and the recorded events.
+    pub fn into_parts(self) -> (H, Vec<RecordedEvent>) {
+        (self.handler, self.events)
+    }
+
+    fn record(&mut self, event: Event) {
+        self.events.push(RecordedEvent {
+            at: self.started_at.elapsed(),
+            event,
+        });
+    }
+}
+
+impl<H: EventHandler> EventHandler for Recorder<H> {
+    fn handle_event(&mut self, event: Event) {
+        self.record(event.clone());
+        self.handler.handle_event(event);
+    }
+
+    fn handle_key(&mut self, event: KeyPressedEvent) {
+        self.record(Event::KeyPressed(event.clone()));
+        self.handler.handle_key(event);
+    }
+
+    fn handle_key_release(&mut self, event: KeyReleasedEvent) {
+        self.record(Event::KeyReleased(event.clone()));
+        self.handler.handle_key_release(event);
+    }
+
+    fn handle_key_repeat(&mut self, event: KeyPressedEvent) {
+        self.record(Event::KeyRepeated(event.clone()));
+        self.handler.handle_key_repeat(event);
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        self.record(Event::Mouse(event));
+        self.handler.handle_mouse(event);
+    }
+
+    fn handle_paste(&mut self, text: String) {
+        self.record(Event::Paste(text.clone()));
+        self.handler.handle_paste(text);
+    }
+
+    fn handle_resize(&mut self, columns: u16, rows: u16) {
+        self.record(Event::Resize { columns, rows });
+        self.handler.handle_resize(columns, rows);
+    }
+
+    fn handle_focus_change(&mut self, focused: bool) {
+        self.record(if focused {
+            Event::FocusGained
+        } else {
+            Event::FocusLost
+        });
+        self.handler.handle_focus_change(focused);
+    }
+
+    fn handle_tick(&mut self) {
+        self.record(Event::Tick);
+        self.handler.handle_tick();
+    }
+
+    fn handle_timer(&mut self, id: TimerId) {
+        self.record(Event::Timer(id));
+        self.handler.handle_timer(id);
+    }
+
+    #[cfg(feature = "theme-file")]
+    fn handle_theme_change(&mut self, theme: ThemeFile) {
+        self.record(Event::ThemeChanged(theme));
+        self.handler.handle_theme_change(theme);
+    }
+}
+
+/// Feeds recorded events into an [`EventHandler`], one step at a time or all at once
+#[derive(Debug, Clone, Default)]
+pub struct Player {
+    events: Vec<RecordedEvent>,
+    position: usize,
+}
+
+impl Player {
+    pub fn new<I: IntoIterator<Item = RecordedEvent>>(events: I) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+            position: 0,
+        }
+    }
+
+    /// Feeds the next event to the handler, returning the event, or `None` if all events have
+    /// been played.
+    pub fn step<H: EventHandler>(&mut self, handler: &mut H) -> Option<&RecordedEvent> {
+        let recorded = self.events.get(self.position)?;
+        self.position += 1;
+        handler.handle_event(recorded.event.clone());
+        Some(recorded)
+    }
+
+    /// Feeds all remaining events to the handler.
+    pub fn play<H: EventHandler>(&mut self, handler: &mut H) {
+        while self.step(handler).is_some() {}
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.events.len()
+    }
+
+    /// Starts playing from the first event again.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+}
+
+/// A terminal backed by a [`TestBackend`] that renders after each replayed event
+#[derive(Debug)]
+pub struct ReplayTerminal {
+    terminal: Terminal<TestBackend>,
+}
+
+impl ReplayTerminal {
+    pub fn new(width: u16, height: u16) -> Self {
+        let terminal = Terminal::new(TestBackend::new(width, height))
+            .expect("creating a terminal with a test backend is infallible");
+        Self { terminal }
+    }
+
+    /// Renders the handler using the render function and returns the resulting buffer.
+    pub fn render<H, F>(&mut self, handler: &mut H, mut render: F) -> Buffer
+    where
+        F: FnMut(&mut H, Rect, &mut Buffer),
+    {
+        self.terminal
+            .draw(|frame| {
+                let area = frame.area();
+                render(handler, area, frame.buffer_mut());
+            })
+            .expect("drawing to a test backend is infallible");
+        self.terminal.backend().buffer().clone()
+    }
+
+    /// Feeds each event to the handler and renders after each step, returning the buffer rendered
+    /// after each event.
+    pub fn replay<H, F>(
+        &mut self,
+        handler: &mut H,
+        events: &[RecordedEvent],
+        mut render: F,
+    ) -> Vec<Buffer>
+    where
+        H: EventHandler,
+        F: FnMut(&mut H, Rect, &mut Buffer),
+    {
+        let mut player = Player::new(events.iter().cloned());
+        let mut buffers = Vec::with_capacity(events.len());
+        while player.step(handler).is_some() {
+            buffers.push(self.render(handler, &mut render));
+        }
+        buffers
+    }
+
+    /// Replays the events and asserts that the buffer rendered after each event matches the
+    /// expected buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of expected buffers does not match the number of events, or if a
+    /// rendered buffer differs from the expected buffer. The panic message shows the step, the
+    /// event and both buffers.
+    #[track_caller]
+    pub fn assert_replay<H, F>(
+        &mut self,
+        handler: &mut H,
+        events: &[RecordedEvent],
+        expected: &[Buffer],
+        render: F,
+    ) where
+        H: EventHandler,
+        F: FnMut(&mut H, Rect, &mut Buffer),
+    {
+        assert_eq!(
+            events.len(),
+            expected.len(),
+            "expected one buffer per recorded event"
+        );
+        let buffers = self.replay(handler, events, render);
+        for (step, (recorded, (actual, expected))) in
+            events.iter().zip(buffers.iter().zip(expected)).enumerate()
+        {
+            assert_eq!(
+                actual, expected,
+                "buffer differs after step {step} ({:?})",
+                recorded.event
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::button::Button;
+    use crate::events::{Key, KeyModifiers, KeyPressedEvent, KeyReleasedEvent};
+
+    #[derive(Debug, Default)]
+    struct KeyCounter {
+        keys: Vec<KeyPressedEvent>,
+    }
+
+    impl EventHandler for KeyCounter {
+        fn handle_key(&mut self, event: KeyPressedEvent) {
+            self.keys.push(event);
+        }
+    }
+
+    fn key_event(c: char) -> Event {
+        Event::KeyPressed(Key::Char(c).into())
+    }
+
+    #[test]
+    fn recorder_records_and_forwards() {
+        let mut recorder = Recorder::new(KeyCounter::default());
+        recorder.handle_event(key_event('a'));
+        recorder.handle_event(Event::FocusLost);
+        recorder.handle_event(key_event('b'));
+
+        let (counter, events) = recorder.into_parts();
+        assert_eq!(counter.keys.len(), 2);
+        let events = events.into_iter().map(|e| e.event).collect::<Vec<_>>();
+        assert_eq!(events, [key_event('a'), Event::FocusLost, key_event('b')]);
+    }
+
+    #[test]
+    fn recorder_records_handler_methods() {
+        let mut recorder = Recorder::new(KeyCounter::default());
+        recorder.handle_key(Key::Char('a').into());
+        recorder.handle_paste("hello".into());
+        recorder.handle_focus_change(true);
+        recorder.handle_tick();
+
+        let (counter, events) = recorder.into_parts();
+        assert_eq!(counter.keys.len(), 1);
+        let events = events.into_iter().map(|e| e.event).collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            [
+                key_event('a'),
+                Event::Paste("hello".into()),
+                Event::FocusGained,
+                Event::Tick
+            ]
+        );
+    }
+
+    #[test]
+    fn player_steps_through_events() {
+        let events = ['a', 'b'].map(|c| RecordedEvent {
+            at: Duration::ZERO,
+            event: key_event(c),
+        });
+        let mut player = Player::new(events);
+        let mut counter = KeyCounter::default();
+
+        assert_eq!(player.step(&mut counter).unwrap().event, key_event('a'));
+        assert!(!player.is_finished());
+        player.play(&mut counter);
+        assert!(player.is_finished());
+        assert!(player.step(&mut counter).is_none());
+        assert_eq!(counter.keys.len(), 2);
+
+        player.rewind();
+        player.play(&mut counter);
+        assert_eq!(counter.keys.len(), 4);
+    }
+
+    #[test]
+    fn replay_renders_after_each_step() {
+        let mut recorder = Recorder::new(Button::new("OK"));
+        recorder.handle_event(key_event(' '));
+        recorder.handle_event(Event::KeyReleased(KeyReleasedEvent {
+            key: Key::Char(' '),
+            modifiers: KeyModifiers::empty(),
+        }));
+        let (_, events) = recorder.into_parts();
+
+        let render = |button: &mut Button, area, buf: &mut Buffer| button.render(area, buf);
+        let mut pressed = Button::new("OK");
+        pressed.press();
+        let mut selected = Button::new("OK");
+        selected.select();
+        let expected = |button: &Button| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+            button.render(buf.area, &mut buf);
+            buf
+        };
+
+        let mut terminal = ReplayTerminal::new(10, 3);
+        terminal.assert_replay(
+            &mut Button::new("OK"),
+            &events,
+            &[expected(&pressed), expected(&selected)],
+            render,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "buffer differs after step 0")]
+    fn assert_replay_reports_step() {
+        let events = [RecordedEvent {
+            at: Duration::ZERO,
+            event: key_event(' '),
+        }];
+        let mut terminal = ReplayTerminal::new(10, 3);
+        terminal.assert_replay(
+            &mut Button::new("OK"),
+            &events,
+            &[Buffer::empty(Rect::new(0, 0, 10, 3))],
+            |button, area, buf| button.render(area, buf),
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let events = vec![
+            RecordedEvent {
+                at: Duration::from_millis(5),
+                event: key_event('q'),
+            },
+            RecordedEvent {
+                at: Duration::from_millis(20),
+                event: Event::Paste("hello".into()),
+            },
+        ];
+        let json = serde_json::to_string(&events).unwrap();
+        let parsed: Vec<RecordedEvent> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, events);
+    }
+}
@@ -0,0 +1,333 @@
+//! An application runner that owns the terminal and the event loop.
+//!
+//! [`run`] sets up the terminal, enables mouse capture and bracketed paste, and then repeatedly
+//! draws the root widget, reads an event and dispatches it to the root widget's
+//! [`EventHandler`] until a handler calls [`request_quit`]. The terminal is restored when the
+//! runner returns, including when it returns because of an error or a panic.
+//!
+//! The runners dispatch an [`Event::Tick`] when no other event arrives within [`TICK_RATE`], so
+//! that time based behavior (e.g. a button releasing itself) is updated while the user is idle.
+//! With the `theme-file` feature, [`watch_theme`] makes the runner reload a theme file on each tick
+//! and dispatch an `Event::ThemeChanged` when it changes.
+//!
+//! [`run`] uses the first enabled backend of crossterm, termion and termwiz. Use [`run_crossterm`],
+//! [`run_termion`] or [`run_termwiz`] to pick a specific backend, or [`run_loop`] to drive a
+//! terminal that was set up elsewhere.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use ratatui::{prelude::*, widgets::Widget};
+//! use ratatui_widgets::events::{EventHandler, Key, KeyPressedEvent};
+//! use ratatui_widgets::runner;
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!     count: u32,
+//! }
+//!
+//! impl Widget for &mut Counter {
+//!     fn render(self, area: Rect, buf: &mut Buffer) {
+//!         Line::raw(format!("Count: {} (q to quit)", self.count)).render(area, buf);
+//!     }
+//! }
+//!
+//! impl EventHandler for Counter {
+//!     fn handle_key(&mut self, event: KeyPressedEvent) {
+//!         match event.key {
+//!             Key::Char('q') => runner::request_quit(),
+//!             _ => self.count += 1,
+//!         }
+//!     }
+//! }
+//!
+//! let counter = runner::run(Counter::default())?;
+//! println!("Counted {} keys", counter.count);
+//! # std::io::Result::Ok(())
+//! ```
+//!
+//! [`run_crossterm`]: crate::runner::run_crossterm
+//! [`run_termion`]: crate::runner::run_termion
+//! [`run_termwiz`]: crate::runner::run_termwiz
+
+#[cfg(feature = "theme-file")]
+use std::cell::RefCell;
+use std::{cell::Cell, io, time::Duration};
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+use std::{panic, sync::Arc};
+
+use ratatui::{backend::Backend, widgets::Widget, Terminal};
+
+use crate::events::{Event, EventHandler};
+#[cfg(feature = "theme-file")]
+use crate::theme::file::{ThemeFile, Watcher};
+
+#[cfg(feature = "crossterm")]
+mod crossterm;
+#[cfg(feature = "crossterm")]
+pub use self::crossterm::run_crossterm;
+
+#[cfg(feature = "termion")]
+mod termion;
+#[cfg(feature = "termion")]
+pub use self::termion::run_termion;
+
+#[cfg(feature = "termwiz")]
+mod termwiz;
+#[cfg(feature = "termwiz")]
+pub use self::termwiz::run_termwiz;
+
+/// How long the runners wait for an event before dispatching a [`Event::Tick`].
+pub const TICK_RATE: Duration = Duration::from_millis(50);
+
+thread_local! {
+    static QUIT_REQUESTED: Cell<bool> = const { Cell::new(false) };
+}
+
+#[cfg(feature = "theme-file")]
+thread_local! {
+    static THEME_WATCHER: RefCell<Option<Watcher>> = const { RefCell::new(None) };
+}
+
+/// Asks the runner to stop after the current event has been handled.
+///
+/// This can be called from any event handler running on the runner's thread.
+pub fn request_quit() {
+    QUIT_REQUESTED.set(true);
+}
+
+/// Makes the runner poll the watcher on each tick, dispatching an [`Event::ThemeChanged`] after
+/// the tick when the theme file has been reloaded. This replaces any watcher set earlier.
+///
+/// A file that fails to load is skipped, so the widgets keep their theme until the file is fixed.
+/// Poll a [`Watcher`] directly to report the error instead.
+///
+/// Like [`request_quit`], this applies to the runner on the current thread.
+#[cfg(feature = "theme-file")]
+pub fn watch_theme(watcher: Watcher) {
+    THEME_WATCHER.set(Some(watcher));
+}
+
+/// Runs the root widget using the first enabled backend until a handler calls [`request_quit`],
+/// returning the root widget.
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+pub fn run<W>(root: W) -> io::Result<W>
+where
+    W: EventHandler,
+    for<'a> &'a mut W: Widget,
+{
+    #[cfg(feature = "crossterm")]
+    return run_crossterm(root);
+    #[cfg(all(not(feature = "crossterm"), feature = "termion"))]
+    return run_termion(root);
+    #[cfg(all(not(feature = "crossterm"), not(feature = "termion")))]
+    return run_termwiz(root);
+}
+
+/// Draws the root widget, reads an event and dispatches it, until a handler calls
+/// [`request_quit`].
+///
+/// `read_event` blocks until the next event is available, or returns [`Event::Tick`] after a
+/// timeout. It returns `None` for events that have no equivalent [`Event`], which are skipped.
+/// The terminal is not set up or restored.
+pub fn run_loop<B, W, F>(
+    terminal: &mut Terminal<B>,
+    root: &mut W,
+    mut read_event: F,
+) -> io::Result<()>
+where
+    B: Backend,
+    W: EventHandler,
+    for<'a> &'a mut W: Widget,
+    F: FnMut(&mut Terminal<B>) -> io::Result<Option<Event>>,
+{
+    QUIT_REQUESTED.set(false);
+    loop {
+        terminal.draw(|frame| frame.render_widget(&mut *root, frame.area()))?;
+        if let Some(event) = read_event(terminal)? {
+            #[cfg(feature = "theme-file")]
+            let theme_change = (event == Event::Tick).then(reload_theme).flatten();
+            root.handle_event(event);
+            #[cfg(feature = "theme-file")]
+            if let Some(theme) = theme_change {
+                root.handle_event(Event::ThemeChanged(theme));
+            }
+        }
+        if QUIT_REQUESTED.replace(false) {
+            return Ok(());
+        }
+    }
+}
+
+/// Polls the watched theme file, returning the reloaded theme if it changed and loaded.
+#[cfg(feature = "theme-file")]
+fn reload_theme() -> Option<ThemeFile> {
+    THEME_WATCHER.with_borrow_mut(|watcher| watcher.as_mut()?.poll()?.ok())
+}
+
+/// Disables bracketed paste and mouse reporting, leaves the alternate screen and shows the cursor,
+/// for backends whose terminal modes can only be fully restored by dropping the terminal.
+#[cfg(any(feature = "termion", feature = "termwiz"))]
+fn reset_terminal_modes() {
+    use std::io::Write;
+
+    const RESET: &[u8] =
+        b"\x1b[?2004l\x1b[?1006l\x1b[?1003l\x1b[?1002l\x1b[?1000l\x1b[?1049l\x1b[?25h";
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(RESET).and_then(|()| stdout.flush());
+}
+
+// `PanicHookInfo` replaced `PanicInfo` in Rust 1.81, which is newer than the MSRV
+#[allow(deprecated)]
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+type PanicHook = Box<dyn Fn(&panic::PanicInfo<'_>) + Send + Sync>;
+
+/// Restores the terminal before the panic message is printed, and reinstates the previous panic
+/// hook when dropped.
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+struct RestoreOnPanic {
+    previous: Arc<PanicHook>,
+}
+
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+impl RestoreOnPanic {
+    fn install<F: Fn() + Send + Sync + 'static>(restore: F) -> Self {
+        let previous = Arc::new(panic::take_hook());
+        let hook = Arc::clone(&previous);
+        panic::set_hook(Box::new(move |info| {
+            restore();
+            hook(info);
+        }));
+        Self { previous }
+    }
+}
+
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+impl Drop for RestoreOnPanic {
+    fn drop(&mut self) {
+        // the panic hook cannot be changed while panicking
+        if std::thread::panicking() {
+            return;
+        }
+        let previous = Arc::clone(&self.previous);
+        panic::set_hook(Box::new(move |info| previous(info)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use ratatui::{backend::TestBackend, buffer::Buffer, layout::Rect, text::Line};
+
+    use super::*;
+    use crate::events::{Key, KeyPressedEvent};
+
+    #[derive(Debug, Default)]
+    struct Counter {
+        count: u32,
+    }
+
+    impl Widget for &mut Counter {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            Line::raw(format!("Count: {}", self.count)).render(area, buf);
+        }
+    }
+
+    impl EventHandler for Counter {
+        fn handle_key(&mut self, event: KeyPressedEvent) {
+            match event.key {
+                Key::Char('q') => request_quit(),
+                _ => self.count += 1,
+            }
+        }
+    }
+
+    #[test]
+    fn run_loop_dispatches_until_quit() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let mut counter = Counter::default();
+        let mut events = VecDeque::from([
+            Some(Event::KeyPressed(Key::Char('a').into())),
+            None,
+            Some(Event::KeyPressed(Key::Char('b').into())),
+            Some(Event::KeyPressed(Key::Char('q').into())),
+            Some(Event::KeyPressed(Key::Char('c').into())),
+        ]);
+
+        run_loop(&mut terminal, &mut counter, |_| {
+            Ok(events.pop_front().unwrap())
+        })
+        .unwrap();
+
+        assert_eq!(counter.count, 2);
+        assert_eq!(events.len(), 1);
+        terminal.backend().assert_buffer_lines(["Count: 2  "]);
+    }
+
+    #[cfg(feature = "theme-file")]
+    #[test]
+    fn run_loop_dispatches_theme_changes_after_ticks() {
+        use std::{fs, time::SystemTime};
+
+        #[derive(Default)]
+        struct Themed(Vec<Event>);
+
+        impl Widget for &mut Themed {
+            fn render(self, _area: Rect, _buf: &mut Buffer) {}
+        }
+
+        impl EventHandler for Themed {
+            fn handle_event(&mut self, event: Event) {
+                self.0.push(event);
+            }
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "ratatui-widgets-{}-runner-theme.toml",
+            std::process::id()
+        ));
+        let write = |contents: &str, seconds: u64| {
+            fs::write(&path, contents).unwrap();
+            let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+            let file = fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(modified).unwrap();
+        };
+        write("", 1);
+        watch_theme(Watcher::new(&path));
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let mut themed = Themed::default();
+        let key = Event::KeyPressed(Key::Char('a').into());
+        let contents = "[button]\nshadow = \"red\"\n";
+        let mut reads = 0;
+        run_loop(&mut terminal, &mut themed, |_| {
+            reads += 1;
+            match reads {
+                1 => write(contents, 2),
+                3 => request_quit(),
+                _ => {}
+            }
+            Ok(Some(if reads == 1 { key.clone() } else { Event::Tick }))
+        })
+        .unwrap();
+        THEME_WATCHER.set(None);
+        fs::remove_file(&path).unwrap();
+
+        let theme = ThemeFile::parse(contents, crate::theme::file::Format::Toml).unwrap();
+        assert_eq!(
+            themed.0,
+            [key, Event::Tick, Event::ThemeChanged(theme), Event::Tick]
+        );
+    }
+
+    #[test]
+    fn run_loop_returns_read_errors() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let error = run_loop(&mut terminal, &mut Counter::default(), |_| {
+            Err(io::Error::other("read failed"))
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "read failed");
+    }
+}
@@ -0,0 +1,60 @@
+use std::io::{self, stdout};
+
+use crossterm::{
+    cursor::Show,
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{backend::CrosstermBackend, widgets::Widget, Terminal};
+
+use super::{run_loop, RestoreOnPanic, TICK_RATE};
+use crate::events::{Event, EventHandler};
+
+/// Runs the root widget using the crossterm backend until a handler calls
+/// [`request_quit`](super::request_quit), returning the root widget.
+pub fn run_crossterm<W>(mut root: W) -> io::Result<W>
+where
+    W: EventHandler,
+    for<'a> &'a mut W: Widget,
+{
+    let _restore_on_panic = RestoreOnPanic::install(|| {
+        let _ = restore();
+    });
+    let result = init().and_then(|()| {
+        let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+        run_loop(&mut terminal, &mut root, |_| {
+            if crossterm::event::poll(TICK_RATE)? {
+                Event::from_crossterm(crossterm::event::read()?)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+            } else {
+                Ok(Some(Event::Tick))
+            }
+        })
+    });
+    // report the first error, but always try to restore the terminal
+    let restored = restore();
+    result.and(restored)?;
+    Ok(root)
+}
+
+fn init() -> io::Result<()> {
+    terminal::enable_raw_mode()?;
+    execute!(
+        stdout(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )
+}
+
+fn restore() -> io::Result<()> {
+    execute!(
+        stdout(),
+        DisableBracketedPaste,
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        Show
+    )?;
+    terminal::disable_raw_mode()
+}
@@ -0,0 +1,51 @@
+use std::{
+    io::{self, stdin, stdout},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+};
+
+use ratatui::{backend::TermionBackend, widgets::Widget, Terminal};
+use termion::{
+    input::MouseTerminal, input::TermRead, raw::IntoRawMode, screen::IntoAlternateScreen,
+};
+
+use super::{reset_terminal_modes, run_loop, RestoreOnPanic, TICK_RATE};
+use crate::events::{Event, EventHandler};
+
+/// Runs the root widget using the termion backend until a handler calls
+/// [`request_quit`](super::request_quit), returning the root widget.
+///
+/// Termion does not support bracketed paste, so pasted text arrives as individual key presses.
+/// Termion can only read events by blocking, so they are read on a background thread, which keeps
+/// reading standard input until the next event after the runner returns.
+///
+/// On panic, the terminal leaves the alternate screen before the panic message is printed, but
+/// raw mode is only disabled once the panic unwinds past the runner.
+pub fn run_termion<W>(mut root: W) -> io::Result<W>
+where
+    W: EventHandler,
+    for<'a> &'a mut W: Widget,
+{
+    let _restore_on_panic = RestoreOnPanic::install(reset_terminal_modes);
+    let stdout = MouseTerminal::from(stdout().into_raw_mode()?.into_alternate_screen()?);
+    let mut terminal = Terminal::new(TermionBackend::new(stdout))?;
+    let (sender, events) = mpsc::channel();
+    thread::spawn(move || {
+        for event in stdin().events() {
+            if sender.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    run_loop(&mut terminal, &mut root, |_| {
+        match events.recv_timeout(TICK_RATE) {
+            Ok(event) => Event::from_termion(event?)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+            Err(RecvTimeoutError::Timeout) => Ok(Some(Event::Tick)),
+            Err(RecvTimeoutError::Disconnected) => Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+    })?;
+    // dropping the terminal restores the terminal modes
+    drop(terminal);
+    Ok(root)
+}
@@ -0,0 +1,41 @@
+use std::io;
+
+use ratatui::{backend::TermwizBackend, widgets::Widget, Terminal};
+use termwiz::terminal::Terminal as _;
+
+use super::{reset_terminal_modes, run_loop, RestoreOnPanic, TICK_RATE};
+use crate::events::{Event, EventHandler, TermwizConverter};
+
+/// Runs the root widget using the termwiz backend until a handler calls
+/// [`request_quit`](super::request_quit), returning the root widget.
+///
+/// Termwiz enables mouse reporting and bracketed paste when the terminal supports them. On panic,
+/// the terminal leaves the alternate screen before the panic message is printed, but raw mode is
+/// only disabled once the panic unwinds past the runner.
+pub fn run_termwiz<W>(mut root: W) -> io::Result<W>
+where
+    W: EventHandler,
+    for<'a> &'a mut W: Widget,
+{
+    let _restore_on_panic = RestoreOnPanic::install(reset_terminal_modes);
+    let backend = TermwizBackend::new().map_err(|error| io::Error::other(error.to_string()))?;
+    let mut terminal = Terminal::new(backend)?;
+    let mut converter = TermwizConverter::new();
+    run_loop(&mut terminal, &mut root, |terminal| {
+        let input = terminal
+            .backend_mut()
+            .buffered_terminal_mut()
+            .terminal()
+            .poll_input(Some(TICK_RATE))
+            .map_err(io::Error::other)?;
+        match input {
+            Some(input) => converter
+                .convert(input)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+            None => Ok(Some(Event::Tick)),
+        }
+    })?;
+    // dropping the terminal restores the terminal modes
+    drop(terminal);
+    Ok(root)
+}
@@ -0,0 +1,270 @@
+//! Crate-wide design tokens.
+//!
+//! A [`Theme`] holds the colors that widgets derive their own themes from: a set of [`Tones`] for
+//! each [`Intent`], the focus ring and the disabled colors. Widgets that have not been given an
+//! explicit theme (e.g. with [`Button::with_theme`]) use the current theme when they render, so
+//! calling [`set_current`] restyles the whole application.
+//!
+//! The [`presets`] module provides dark, light and high contrast themes. With the `theme-file`
+//! feature, the [`file`](mod@file) module loads widget themes from TOML or JSON files.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui_widgets::color::ColorDepth;
+//! use ratatui_widgets::theme::{self, presets};
+//!
+//! theme::set_current(presets::LIGHT.for_color_depth(ColorDepth::detect()));
+//! ```
+//!
+//! [`Button::with_theme`]: crate::button::Button::with_theme
+
+use std::cell::Cell;
+
+use ratatui::style::{palette::tailwind::Palette, Color};
+
+use crate::color::ColorDepth;
+
+#[cfg(feature = "theme-file")]
+pub mod file;
+
+thread_local! {
+    static CURRENT: Cell<Theme> = const { Cell::new(presets::DARK) };
+}
+
+/// Returns the theme that widgets without an explicit theme render with.
+pub fn current() -> Theme {
+    CURRENT.get()
+}
+
+/// Sets the theme that widgets without an explicit theme render with, from the next render on.
+///
+/// The theme is set for the current thread, which is the thread that renders.
+pub fn set_current(theme: Theme) {
+    CURRENT.set(theme);
+}
+
+/// The purpose of a widget, which decides which tones it uses
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Intent {
+    /// The main action, or a widget that is switched on
+    Primary,
+    #[default]
+    Neutral,
+    /// A destructive action
+    Danger,
+    /// A confirming action
+    Success,
+}
+
+/// The design tokens that widget themes are derived from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    pub primary: Tones,
+    pub neutral: Tones,
+    pub danger: Tones,
+    pub success: Tones,
+    /// The color that marks the focused widget, e.g. the border of a focused button
+    pub focus_ring: Color,
+    pub disabled_text: Color,
+    pub disabled_background: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        presets::DARK
+    }
+}
+
+impl Theme {
+    pub const fn tones(&self, intent: Intent) -> &Tones {
+        match intent {
+            Intent::Primary => &self.primary,
+            Intent::Neutral => &self.neutral,
+            Intent::Danger => &self.danger,
+            Intent::Success => &self.success,
+        }
+    }
+
+    /// Replaces each color with the nearest color the terminal can display.
+    pub fn for_color_depth(self, depth: ColorDepth) -> Self {
+        Self {
+            primary: self.primary.for_color_depth(depth),
+            neutral: self.neutral.for_color_depth(depth),
+            danger: self.danger.for_color_depth(depth),
+            success: self.success.for_color_depth(depth),
+            focus_ring: depth.adapt(self.focus_ring),
+            disabled_text: depth.adapt(self.disabled_text),
+            disabled_background: depth.adapt(self.disabled_background),
+        }
+    }
+}
+
+/// The colors of one intent, for each state a widget can be in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tones {
+    pub text: Color,
+    pub background: Color,
+    pub hovered_text: Color,
+    pub hovered_background: Color,
+    pub focused_text: Color,
+    pub focused_background: Color,
+    pub pressed_text: Color,
+    pub pressed_background: Color,
+    /// The text of a widget that is switched on
+    pub active_text: Color,
+    /// The background of a widget that is switched on
+    pub active_background: Color,
+    /// The lit edge of a raised widget
+    pub highlight: Color,
+    /// The shaded edge of a raised widget
+    pub shadow: Color,
+}
+
+impl Tones {
+    /// Light text on dark shades of the palette
+    pub const fn dark(palette: &Palette) -> Self {
+        Self {
+            text: palette.c200,
+            background: palette.c800,
+            hovered_text: palette.c50,
+            hovered_background: palette.c800,
+            focused_text: palette.c100,
+            focused_background: palette.c700,
+            pressed_text: palette.c300,
+            pressed_background: palette.c900,
+            active_text: palette.c950,
+            active_background: palette.c400,
+            highlight: palette.c600,
+            shadow: palette.c950,
+        }
+    }
+
+    /// Dark text on light shades of the palette
+    pub const fn light(palette: &Palette) -> Self {
+        Self {
+            text: palette.c800,
+            background: palette.c200,
+            hovered_text: palette.c950,
+            hovered_background: palette.c100,
+            focused_text: palette.c900,
+            focused_background: palette.c300,
+            pressed_text: palette.c700,
+            pressed_background: palette.c400,
+            active_text: palette.c50,
+            active_background: palette.c600,
+            highlight: palette.c50,
+            shadow: palette.c400,
+        }
+    }
+
+    /// White text on black, with the color marking the focused and active states
+    pub const fn high_contrast(color: Color) -> Self {
+        Self {
+            text: Color::White,
+            background: Color::Black,
+            hovered_text: color,
+            hovered_background: Color::Black,
+            focused_text: Color::Black,
+            focused_background: color,
+            pressed_text: Color::Black,
+            pressed_background: Color::Gray,
+            active_text: Color::Black,
+            active_background: color,
+            highlight: Color::White,
+            shadow: Color::DarkGray,
+        }
+    }
+
+    /// Replaces each color with the nearest color the terminal can display.
+    pub fn for_color_depth(self, depth: ColorDepth) -> Self {
+        Self {
+            text: depth.adapt(self.text),
+            background: depth.adapt(self.background),
+            hovered_text: depth.adapt(self.hovered_text),
+            hovered_background: depth.adapt(self.hovered_background),
+            focused_text: depth.adapt(self.focused_text),
+            focused_background: depth.adapt(self.focused_background),
+            pressed_text: depth.adapt(self.pressed_text),
+            pressed_background: depth.adapt(self.pressed_background),
+            active_text: depth.adapt(self.active_text),
+            active_background: depth.adapt(self.active_background),
+            highlight: depth.adapt(self.highlight),
+            shadow: depth.adapt(self.shadow),
+        }
+    }
+}
+
+pub mod presets {
+    use ratatui::style::{palette::tailwind, Color};
+
+    use super::{Theme, Tones};
+
+    pub const DARK: Theme = Theme {
+        primary: Tones::dark(&tailwind::BLUE),
+        neutral: Tones::dark(&tailwind::GRAY),
+        danger: Tones::dark(&tailwind::RED),
+        success: Tones::dark(&tailwind::GREEN),
+        focus_ring: tailwind::BLUE.c300,
+        disabled_text: tailwind::GRAY.c500,
+        disabled_background: tailwind::GRAY.c900,
+    };
+
+    pub const LIGHT: Theme = Theme {
+        primary: Tones::light(&tailwind::BLUE),
+        neutral: Tones::light(&tailwind::GRAY),
+        danger: Tones::light(&tailwind::RED),
+        success: Tones::light(&tailwind::GREEN),
+        focus_ring: tailwind::BLUE.c700,
+        disabled_text: tailwind::GRAY.c400,
+        disabled_background: tailwind::GRAY.c100,
+    };
+
+    /// Uses the 16 ANSI colors, so the terminal's own color scheme still applies
+    pub const HIGH_CONTRAST: Theme = Theme {
+        primary: Tones::high_contrast(Color::LightCyan),
+        neutral: Tones::high_contrast(Color::White),
+        danger: Tones::high_contrast(Color::LightRed),
+        success: Tones::high_contrast(Color::LightGreen),
+        focus_ring: Color::LightYellow,
+        disabled_text: Color::DarkGray,
+        disabled_background: Color::Black,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_current() {
+        assert_eq!(current(), presets::DARK);
+        super::set_current(presets::LIGHT);
+        assert_eq!(current(), presets::LIGHT);
+        super::set_current(presets::DARK);
+    }
+
+    #[test]
+    fn tones() {
+        let theme = presets::HIGH_CONTRAST;
+        assert_eq!(
+            theme.tones(Intent::Danger).focused_background,
+            Color::LightRed
+        );
+        assert_eq!(theme.tones(Intent::default()), &theme.neutral);
+    }
+
+    #[test]
+    fn for_color_depth() {
+        let theme = presets::DARK.for_color_depth(ColorDepth::Ansi16);
+        assert!(!matches!(theme.primary.background, Color::Rgb(..)));
+        assert!(!matches!(theme.focus_ring, Color::Rgb(..)));
+        assert_eq!(
+            presets::HIGH_CONTRAST.for_color_depth(ColorDepth::Ansi16),
+            presets::HIGH_CONTRAST
+        );
+    }
+}
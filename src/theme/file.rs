@@ -0,0 +1,346 @@
+//! Widget themes loaded from TOML or JSON files.
+//!
+//! A [`ThemeFile`] holds a [`button::Theme`] and a [`toggle_switch::Theme`]. Each field is a color,
+//! written as a name (`"light-blue"`), an RGB hex value (`"#1e40af"`) or an index into the 256
+//! color palette (`"33"`). Fields that are left out keep the colors of the default themes, and
+//! unknown fields are reported as errors.
+//!
+//! ```toml
+//! [button]
+//! normal_text = "white"
+//! normal_background = "#1e40af"
+//! focus_ring = "yellow"
+//!
+//! [toggle_switch]
+//! focused_on_bg_main = "33"
+//! ```
+//!
+//! A [`Watcher`] reloads a theme file when it changes, so that a running application can pick up
+//! the new colors. Pass it to [`runner::watch_theme`] (or `EventStream::with_theme_watcher` with
+//! the `tokio` feature) to receive an [`Event::ThemeChanged`] in
+//! [`EventHandler::handle_theme_change`] each time the file is reloaded, or poll it directly.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use ratatui::{prelude::*, widgets::Widget};
+//! use ratatui_widgets::events::EventHandler;
+//! use ratatui_widgets::runner;
+//! use ratatui_widgets::theme::file::{ThemeFile, Watcher};
+//! use ratatui_widgets::Button;
+//!
+//! struct App {
+//!     button: Button<'static>,
+//! }
+//!
+//! impl EventHandler for App {
+//!     fn handle_theme_change(&mut self, theme: ThemeFile) {
+//!         self.button = self.button.clone().with_theme(theme.button);
+//!     }
+//! }
+//!
+//! impl Widget for &mut App {
+//!     fn render(self, area: Rect, buf: &mut Buffer) {
+//!         (&self.button).render(area, buf);
+//!     }
+//! }
+//!
+//! let theme = ThemeFile::load("theme.toml")?;
+//! let button = Button::new("OK").with_theme(theme.button);
+//! runner::watch_theme(Watcher::new("theme.toml"));
+//! runner::run(App { button })?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`runner::watch_theme`]: crate::runner::watch_theme
+//! [`Event::ThemeChanged`]: crate::events::Event::ThemeChanged
+//! [`EventHandler::handle_theme_change`]: crate::events::EventHandler::handle_theme_change
+//! [`button::Theme`]: crate::button::Theme
+//! [`toggle_switch::Theme`]: crate::toggle_switch::Theme
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use thiserror::Error;
+
+use crate::{button, toggle_switch};
+
+/// The themes of each widget, as read from a theme file
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeFile {
+    pub button: button::Theme,
+    pub toggle_switch: toggle_switch::Theme,
+}
+
+/// The format of a theme file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    Toml,
+    Json,
+}
+
+impl Format {
+    /// Returns the format matching the extension of the path (`.toml` or `.json`).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        if extension.eq_ignore_ascii_case("toml") {
+            Some(Self::Toml)
+        } else if extension.eq_ignore_ascii_case("json") {
+            Some(Self::Json)
+        } else {
+            None
+        }
+    }
+}
+
+/// An error loading a theme file
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("failed to read theme file {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error(
+        "unknown format of theme file {}, expected a .toml or .json extension",
+        path.display()
+    )]
+    UnknownFormat { path: PathBuf },
+    #[error("invalid theme file {}: {source}", path.display())]
+    Parse { path: PathBuf, source: ParseError },
+}
+
+/// An error parsing the contents of a theme file
+///
+/// The message names the line and the key that is wrong, e.g. an unknown field or a value that
+/// is not a color.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+impl ThemeFile {
+    /// Reads a theme file, picking the format from the extension of the path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let format = Format::from_path(path).ok_or_else(|| Error::UnknownFormat {
+            path: path.to_path_buf(),
+        })?;
+        let contents = fs::read_to_string(path).map_err(|source| Error::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&contents, format).map_err(|source| Error::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Parses the contents of a theme file.
+    pub fn parse(contents: &str, format: Format) -> Result<Self, ParseError> {
+        match format {
+            Format::Toml => Ok(toml::from_str(contents)?),
+            Format::Json => Ok(serde_json::from_str(contents)?),
+        }
+    }
+}
+
+/// Watches a theme file, reloading it each time it is modified
+///
+/// [`Watcher::poll`] checks whether the file has changed since it was last polled, and reloads it
+/// if so. Each poll reads the metadata of the file, and a change is detected when its modification
+/// time or length differs. The runner polls a watcher registered with [`runner::watch_theme`] on
+/// each tick and dispatches an [`Event::ThemeChanged`] when the file is reloaded.
+///
+/// [`runner::watch_theme`]: crate::runner::watch_theme
+/// [`Event::ThemeChanged`]: crate::events::Event::ThemeChanged
+#[derive(Debug, Clone)]
+pub struct Watcher {
+    path: PathBuf,
+    version: Option<Version>,
+}
+
+/// The modification time and length of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Version {
+    modified: SystemTime,
+    len: u64,
+}
+
+impl Watcher {
+    /// Watches the file at the path. Changes made before the watcher is created are not reported.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let version = Version::of(&path);
+        Self { path, version }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reloads the file if it changed since the last poll, returning the reloaded file or the
+    /// error loading it.
+    pub fn poll(&mut self) -> Option<Result<ThemeFile, Error>> {
+        let version = Version::of(&self.path);
+        if version == self.version {
+            return None;
+        }
+        self.version = version;
+        // the file is missing while some editors replace it, so wait for it to come back
+        version?;
+        Some(ThemeFile::load(&self.path))
+    }
+}
+
+impl Version {
+    fn of(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self {
+            modified: metadata.modified().ok()?,
+            len: metadata.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    /// Returns a path in the temp directory that is unique to the test.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ratatui-widgets-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn parse_toml() {
+        let contents = r##"
+            [button]
+            normal_text = "light-blue"
+            normal_background = "#1e40af"
+            focus_ring = "33"
+
+            [toggle_switch]
+            focused_on_fg = "black"
+        "##;
+        let theme = ThemeFile::parse(contents, Format::Toml).unwrap();
+        let button = button::Theme::default()
+            .with_normal_text(Color::LightBlue)
+            .with_normal_background(Color::Rgb(0x1e, 0x40, 0xaf))
+            .with_focus_ring(Color::Indexed(33));
+        assert_eq!(theme.button, button);
+
+        let json = r#"{ "toggle_switch": { "focused_on_fg": "black" } }"#;
+        let toggle_switch = ThemeFile::parse(json, Format::Json).unwrap().toggle_switch;
+        assert_ne!(theme.toggle_switch, toggle_switch::Theme::default());
+        assert_eq!(theme.toggle_switch, toggle_switch);
+    }
+
+    #[test]
+    fn parse_json() {
+        let contents = r#"{ "button": { "shadow": "dark-gray" } }"#;
+        let theme = ThemeFile::parse(contents, Format::Json).unwrap();
+        assert_eq!(
+            theme.button,
+            button::Theme::default().with_shadow(Color::DarkGray)
+        );
+        assert_eq!(theme.toggle_switch, toggle_switch::Theme::default());
+    }
+
+    #[test]
+    fn defaults_match_new_widgets() {
+        let json = r#"{ "toggle_switch": { "focused_on_fg": "black" } }"#;
+        let theme = ThemeFile::parse(json, Format::Json).unwrap();
+        let tokens = crate::theme::current();
+        let expected = toggle_switch::Theme::from_tokens(&tokens, crate::theme::Intent::Primary);
+        let mut parsed = serde_json::to_value(theme.toggle_switch).unwrap();
+        let expected = serde_json::to_value(expected).unwrap();
+        assert_eq!(parsed["focused_on_fg"], "Black");
+        parsed["focused_on_fg"] = expected["focused_on_fg"].clone();
+        assert_eq!(parsed, expected);
+
+        let expected = button::Theme::from_tokens(&tokens, crate::theme::Intent::Neutral);
+        assert_eq!(theme.button, expected);
+    }
+
+    #[test]
+    fn unknown_field() {
+        let contents = "[button]\nnormal_txt = \"red\"\n";
+        let err = ThemeFile::parse(contents, Format::Toml).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("line 2"), "{message}");
+        assert!(message.contains("unknown field `normal_txt`"), "{message}");
+
+        let err = ThemeFile::parse(r#"{ "buton": {} }"#, Format::Json).unwrap_err();
+        assert!(err.to_string().contains("unknown field `buton`"), "{err}");
+    }
+
+    #[test]
+    fn invalid_color() {
+        let contents = "[button]\nnormal_text = \"not a color\"\n";
+        let err = ThemeFile::parse(contents, Format::Toml).unwrap_err();
+        assert!(err.to_string().contains("normal_text"), "{err}");
+    }
+
+    #[test]
+    fn load() {
+        let err = ThemeFile::load("theme.yaml").unwrap_err();
+        assert!(matches!(err, Error::UnknownFormat { .. }), "{err}");
+
+        let path = temp_path("missing.toml");
+        let err = ThemeFile::load(&path).unwrap_err();
+        assert!(matches!(err, Error::Read { .. }), "{err}");
+
+        let path = temp_path("load.json");
+        fs::write(&path, r#"{ "button": { "highlight": "red" } }"#).unwrap();
+        let theme = ThemeFile::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            theme.unwrap().button,
+            button::Theme::default().with_highlight(Color::Red)
+        );
+    }
+
+    #[test]
+    fn watcher() {
+        let path = temp_path("watch.toml");
+        // set the modification time explicitly, as writes in quick succession may share one
+        let write = |contents: &str, seconds: u64| {
+            fs::write(&path, contents).unwrap();
+            let modified = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(seconds);
+            let file = fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(modified).unwrap();
+        };
+
+        write("", 1);
+        let mut watcher = Watcher::new(&path);
+        assert!(watcher.poll().is_none());
+
+        write("[button]\nshadow = \"red\"\n", 2);
+        let theme = watcher.poll().unwrap().unwrap();
+        assert_eq!(
+            theme.button,
+            button::Theme::default().with_shadow(Color::Red)
+        );
+        assert!(watcher.poll().is_none());
+
+        write("[button]\nshadow = 1.5\n", 3);
+        let err = watcher.poll().unwrap().unwrap_err();
+        assert!(matches!(err, Error::Parse { .. }), "{err}");
+
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.poll().is_none());
+        write("", 4);
+        let theme = watcher.poll().unwrap().unwrap();
+        assert_eq!(theme, ThemeFile::default());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,9 +1,13 @@
-use crate::events::{EventHandler, Key, KeyPressedEvent};
+use crate::color::{self, ColorDepth};
+use crate::events::{EventHandler, Key, KeyPressedEvent, MouseButton, MouseEvent, MouseEventKind};
+use crate::focus::Focusable;
+use crate::keymap::Keymap;
+use crate::theme::{self as tokens, Intent};
 use itertools::Itertools;
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
-    style::{Color, Stylize},
+    style::{Color, Modifier, Stylize},
     text::Text,
     widgets::Widget,
 };
@@ -20,15 +24,18 @@ use ratatui::{
 ///
 /// # fn draw(frame: &mut ratatui::Frame) {
 /// let toggle_switch = ToggleSwitch::new("Toggle me", State::Off);
-/// frame.render_widget(toggle_switch, frame.area());
+/// frame.render_widget(&toggle_switch, frame.area());
 /// # }
 /// ```
 #[derive(Debug, Clone)]
 pub struct ToggleSwitch<'text> {
     text: Text<'text>,
-    theme: Theme,
+    /// An explicit theme, otherwise the theme is derived from the current crate-wide theme
+    theme: Option<Theme>,
+    intent: Intent,
     state: State,
     focus: Focus,
+    keymap: Keymap<Action>,
 }
 
 #[derive(Default, PartialEq, Eq, Clone, Debug, Copy)]
@@ -44,7 +51,9 @@ pub enum Focus {
     Unfocused,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct Theme {
     focused_text: Color,
 
@@ -71,35 +80,140 @@ pub struct Theme {
     unfocused_off_bg_shadow: Color,
 }
 
+/// The theme that a switch without an explicit theme renders with: the [`Intent::Primary`] tones of
+/// the current crate-wide theme. Theme files fill in the colors they leave out from this theme.
 impl Default for Theme {
     fn default() -> Self {
-        themes::NORMAL
+        Self::from_tokens(&tokens::current(), Intent::Primary)
     }
 }
 
+impl Theme {
+    /// Builds a theme from the crate-wide design tokens, using the tones of the intent for the on
+    /// half of the switch and the neutral tones for the off half.
+    pub const fn from_tokens(tokens: &tokens::Theme, intent: Intent) -> Self {
+        let on = tokens.tones(intent);
+        let off = &tokens.neutral;
+        Self {
+            focused_text: off.focused_text,
+
+            focused_on_fg: on.active_text,
+            focused_on_bg_main: on.active_background,
+            focused_on_bg_highlight: on.highlight,
+            focused_on_bg_shadow: on.shadow,
+
+            focused_off_fg: off.focused_text,
+            focused_off_bg_main: off.focused_background,
+            focused_off_bg_highlight: off.highlight,
+            focused_off_bg_shadow: off.shadow,
+
+            unfocused_text: off.text,
+
+            unfocused_on_fg: on.text,
+            unfocused_on_bg_main: on.background,
+            unfocused_on_bg_highlight: on.highlight,
+            unfocused_on_bg_shadow: on.shadow,
+
+            unfocused_off_fg: off.text,
+            unfocused_off_bg_main: off.background,
+            unfocused_off_bg_highlight: off.highlight,
+            unfocused_off_bg_shadow: off.shadow,
+        }
+    }
+}
+
+/// The actions that can be bound to keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    Toggle,
+    On,
+    Off,
+}
+
+/// The default keymap: space and enter toggle the switch, h / left turn it off and l / right turn
+/// it on
+pub fn default_keymap() -> Keymap<Action> {
+    Keymap::new()
+        .with_binding([Key::Char(' ').into()], Action::Toggle)
+        .with_binding([Key::Enter.into()], Action::Toggle)
+        .with_binding([Key::Char('h').into()], Action::Off)
+        .with_binding([Key::Left.into()], Action::Off)
+        .with_binding([Key::Char('l').into()], Action::On)
+        .with_binding([Key::Right.into()], Action::On)
+}
+
 impl<'text> ToggleSwitch<'text> {
     pub fn new<T: Into<Text<'text>>>(text: T, default_state: State) -> Self {
         Self {
             text: text.into(),
-            theme: Theme::default(),
+            theme: None,
+            intent: Intent::Primary,
             state: default_state,
             focus: Focus::Unfocused,
+            keymap: default_keymap(),
         }
     }
 
+    /// Sets an explicit theme, instead of following the current crate-wide theme.
     pub fn with_theme(mut self, theme: Theme) -> Self {
-        self.theme = theme;
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets which tones of the crate-wide theme the switch uses when it is on. The default is
+    /// [`Intent::Primary`]. This has no effect when the switch has an explicit theme.
+    pub fn with_intent(mut self, intent: Intent) -> Self {
+        self.intent = intent;
+        self
+    }
+
+    pub fn with_keymap(mut self, keymap: Keymap<Action>) -> Self {
+        self.keymap = keymap;
         self
     }
 }
 
 impl EventHandler for ToggleSwitch<'_> {
     fn handle_key(&mut self, key_event: KeyPressedEvent) {
-        match key_event.key {
-            Key::Char(' ') | Key::Enter => self.toggle_state(),
-            Key::Char('h') | Key::Left => self.toggle_off(),
-            Key::Char('l') | Key::Right => self.toggle_on(),
-            _ => {}
+        for action in self.keymap.resolve(key_event) {
+            self.perform(action);
+        }
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if event.kind == MouseEventKind::Down(MouseButton::Left) {
+            self.toggle_state();
+        }
+    }
+
+    fn handle_tick(&mut self) {
+        if let Some(action) = self.keymap.expire() {
+            self.perform(action);
+        }
+    }
+}
+
+impl ToggleSwitch<'_> {
+    fn perform(&mut self, action: Action) {
+        match action {
+            Action::Toggle => self.toggle_state(),
+            Action::Off => self.toggle_off(),
+            Action::On => self.toggle_on(),
+        }
+    }
+}
+
+impl Focusable for ToggleSwitch<'_> {
+    fn is_focused(&self) -> bool {
+        self.focus == Focus::Focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if focused {
+            self.focus();
+        } else {
+            self.blur();
         }
     }
 }
@@ -132,7 +246,13 @@ impl ToggleSwitch<'_> {
 
 impl Widget for &ToggleSwitch<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let theme = self.theme;
+        if color::depth() == ColorDepth::Monochrome {
+            self.render_monochrome(area, buf);
+            return;
+        }
+        let theme = self
+            .theme
+            .unwrap_or_else(|| Theme::from_tokens(&tokens::current(), self.intent));
 
         // TODO: refactor this to use a more generic approach
         let (tick_fg, tick_bg, cross_fg, cross_bg) = match (self.focus, self.state) {
@@ -251,6 +371,32 @@ impl Widget for &ToggleSwitch<'_> {
     }
 }
 
+impl ToggleSwitch<'_> {
+    /// Renders the switch without colors. Only the half of the current state is shown, reversed,
+    /// and the label is bold and underlined when the switch is focused.
+    fn render_monochrome(&self, area: Rect, buf: &mut Buffer) {
+        let [switch, label] = Layout::horizontal([Constraint::Max(10), Constraint::Fill(1)])
+            .spacing(2)
+            .areas(area);
+        let [cross, tick] = Layout::horizontal([Constraint::Fill(1); 2]).areas(switch);
+        let (active, symbol) = match self.state {
+            State::On => (tick, "✓"),
+            State::Off => (cross, "✗"),
+        };
+        buf.set_style(active, Modifier::REVERSED | Modifier::BOLD);
+        if let Some(middle_row) = active.rows().nth(active.height as usize / 2) {
+            Text::from(symbol).centered().render(middle_row, buf);
+        }
+
+        if self.focus == Focus::Focused {
+            buf.set_style(label, Modifier::BOLD | Modifier::UNDERLINED);
+        }
+        if let Some(middle_row) = label.rows().nth(label.height as usize / 2) {
+            self.text.clone().left_aligned().render(middle_row, buf);
+        }
+    }
+}
+
 pub mod themes {
     use super::Theme;
     use ratatui::style::palette::tailwind;